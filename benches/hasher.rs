@@ -0,0 +1,16 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fnv_rs::{Fnv256, Fnv512, Fnv1024, FnvHasher};
+
+fn wide_hashers(c: &mut Criterion) {
+    let bytes: Vec<u8> = (0..64 * 1024u32).map(|i| i.wrapping_mul(0x9E37_79B9) as u8).collect();
+    let mut group = c.benchmark_group("wide_64KiB");
+
+    group.bench_function("Fnv256", |b| b.iter(|| Fnv256::hash(black_box(&bytes))));
+    group.bench_function("Fnv512", |b| b.iter(|| Fnv512::hash(black_box(&bytes))));
+    group.bench_function("Fnv1024", |b| b.iter(|| Fnv1024::hash(black_box(&bytes))));
+
+    group.finish();
+}
+
+criterion_group!(benches, wide_hashers);
+criterion_main!(benches);
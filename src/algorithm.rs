@@ -0,0 +1,277 @@
+//! Choosing an FNV hasher at runtime.
+
+use core::fmt;
+use core::str::FromStr;
+
+use paste::paste;
+
+use crate::{FnvDigest, FnvError, FnvHasher, FnvHashResult, FnvVariant};
+use crate::{Fnv16, Fnv24, Fnv32, Fnv48, Fnv56, Fnv64, Fnv128, Fnv256, Fnv512, Fnv1024};
+use crate::{Fnv1_32, Fnv1_64, Fnv1_128, Fnv1_256, Fnv1_512, Fnv1_1024};
+use crate::{Fnv0_32, Fnv0_64, Fnv0_128, Fnv0_256, Fnv0_512, Fnv0_1024};
+
+macro_rules! create_fnv_algorithm {
+    ($($variant:ident => $hasher:ident, $name:literal, $bits:literal, $fnv:ident;)+) => { paste! {
+        /// Every FNV hasher, selectable at runtime.
+        ///
+        /// Algorithms are named like `fnv1a-64`, `fnv1-128` or `fnv0-32`, and parse from those
+        /// names case-insensitively.
+        ///
+        /// # Example:
+        ///
+        /// ```
+        /// use fnv_rs::FnvAlgorithm;
+        ///
+        /// let algorithm: FnvAlgorithm = "fnv1a-64".parse().unwrap();
+        /// assert_eq!(algorithm, FnvAlgorithm::Fnv1a64);
+        /// assert_eq!(algorithm.to_string(), "fnv1a-64");
+        ///
+        /// let hash = algorithm.hash(b"Hash this!");
+        /// assert_eq!(hash.len(), 8);
+        /// ```
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum FnvAlgorithm {
+            $(
+                #[doc = "`" $name "`, computed by [`" $hasher "`]."]
+                $variant,
+            )+
+        }
+
+        impl FnvAlgorithm {
+            /// Every algorithm, in order of variant and width.
+            pub const ALL: &'static [FnvAlgorithm] = &[$(FnvAlgorithm::$variant),+];
+
+            /// Returns this algorithm's name, e.g. `fnv1a-64`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(FnvAlgorithm::$variant => $name,)+
+                }
+            }
+
+            /// Returns the width of this algorithm's hashes in bits.
+            pub const fn bits(self) -> u32 {
+                match self {
+                    $(FnvAlgorithm::$variant => $bits,)+
+                }
+            }
+
+            /// Returns whether this algorithm is FNV-0, FNV-1 or FNV-1a.
+            pub const fn variant(self) -> FnvVariant {
+                match self {
+                    $(FnvAlgorithm::$variant => FnvVariant::$fnv,)+
+                }
+            }
+
+            /// One-time hash of `bytes` with this algorithm.
+            pub fn hash<T: AsRef<[u8]>>(self, bytes: T) -> FnvHashResult {
+                match self {
+                    $(FnvAlgorithm::$variant => $hasher::hash(bytes).into(),)+
+                }
+            }
+
+            /// Creates a new hasher for this algorithm.
+            ///
+            /// # Example:
+            ///
+            /// ```
+            /// # use fnv_rs::FnvAlgorithm;
+            /// # let bytes = b"Hash this!";
+            /// let mut hasher = FnvAlgorithm::Fnv1a128.new_hasher();
+            /// hasher.update(bytes);
+            /// assert_eq!(hasher.finalize(), FnvAlgorithm::Fnv1a128.hash(bytes));
+            /// ```
+            pub fn new_hasher(self) -> DynFnvHasher {
+                DynFnvHasher::new(self)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        enum DynInner {
+            $($variant($hasher),)+
+        }
+
+        /// An FNV hasher whose algorithm is chosen at runtime.
+        ///
+        /// Unlike the [`FnvHasher`] types, every `DynFnvHasher` has the same type, whatever its
+        /// algorithm, and it returns an [`FnvHashResult`]. It dispatches to the concrete hasher
+        /// without allocating.
+        ///
+        /// # Example:
+        ///
+        /// ```
+        /// # use fnv_rs::{DynFnvHasher, FnvAlgorithm};
+        /// # let bytes = b"Hash this!";
+        /// let algorithm: FnvAlgorithm = "fnv1-32".parse().unwrap();
+        /// let mut hasher = DynFnvHasher::new(algorithm);
+        /// hasher.update(bytes);
+        /// let hash = hasher.finalize();
+        /// ```
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct DynFnvHasher {
+            inner: DynInner,
+        }
+
+        impl DynFnvHasher {
+            /// Creates a new hasher for `algorithm`.
+            pub fn new(algorithm: FnvAlgorithm) -> Self {
+                let inner = match algorithm {
+                    $(FnvAlgorithm::$variant => DynInner::$variant($hasher::new()),)+
+                };
+
+                DynFnvHasher { inner }
+            }
+
+            /// Returns the algorithm this hasher computes.
+            pub fn algorithm(&self) -> FnvAlgorithm {
+                match self.inner {
+                    $(DynInner::$variant(_) => FnvAlgorithm::$variant,)+
+                }
+            }
+
+            /// Incrementally update the hasher.
+            pub fn update(&mut self, bytes: &[u8]) {
+                match &mut self.inner {
+                    $(DynInner::$variant(hasher) => hasher.update(bytes),)+
+                }
+            }
+
+            /// Removes `bytes` from the end of the hashed data, undoing [`update`](DynFnvHasher::update).
+            ///
+            /// See [`Fnv::unupdate`](crate::Fnv::unupdate).
+            pub fn unupdate(&mut self, bytes: &[u8]) {
+                match &mut self.inner {
+                    $(DynInner::$variant(hasher) => hasher.unupdate(bytes),)+
+                }
+            }
+
+            /// Finalize the hasher.
+            pub fn finalize(&self) -> FnvHashResult {
+                match &self.inner {
+                    $(DynInner::$variant(hasher) => hasher.finalize().into(),)+
+                }
+            }
+
+            /// Finalize the hasher, then reset it so it can be reused.
+            pub fn finalize_reset(&mut self) -> FnvHashResult {
+                let hash = self.finalize();
+                self.reset();
+                hash
+            }
+
+            /// Resets the hasher to its initial state, keeping its algorithm.
+            pub fn reset(&mut self) {
+                *self = DynFnvHasher::new(self.algorithm());
+            }
+
+            /// Exports the running hash, to resume hashing later with
+            /// [`from_state`](DynFnvHasher::from_state).
+            ///
+            /// The encoding matches the concrete hasher's `state`. The algorithm is not included.
+            ///
+            /// # Example:
+            ///
+            /// ```
+            /// # use fnv_rs::{DynFnvHasher, FnvAlgorithm};
+            /// let mut hasher = DynFnvHasher::new(FnvAlgorithm::Fnv1a256);
+            /// hasher.update(b"Hash ");
+            /// let saved = hasher.state();
+            ///
+            /// let mut resumed = DynFnvHasher::from_state(FnvAlgorithm::Fnv1a256, saved.as_bytes()).unwrap();
+            /// resumed.update(b"this!");
+            /// assert_eq!(resumed.finalize(), FnvAlgorithm::Fnv1a256.hash(b"Hash this!"));
+            /// ```
+            pub fn state(&self) -> FnvHashResult {
+                match &self.inner {
+                    $(DynInner::$variant(hasher) => hasher.state().into(),)+
+                }
+            }
+
+            /// Resumes hashing with `algorithm` from a state exported by
+            /// [`state`](DynFnvHasher::state).
+            ///
+            /// Fails with [`FnvError::InvalidLength`] if `state` is not the length of `algorithm`'s
+            /// state.
+            pub fn from_state(algorithm: FnvAlgorithm, state: &[u8]) -> Result<Self, FnvError> {
+                let inner = match algorithm {
+                    $(FnvAlgorithm::$variant => {
+                        let state = state.try_into().map_err(|_| FnvError::InvalidLength {
+                            expected: $hasher::new().state().len(),
+                            actual: state.len(),
+                        })?;
+                        DynInner::$variant($hasher::from_state(FnvDigest(state)))
+                    })+
+                };
+
+                Ok(DynFnvHasher { inner })
+            }
+        }
+    }};
+}
+
+create_fnv_algorithm! {
+    Fnv1a16 => Fnv16, "fnv1a-16", 16, Fnv1a;
+    Fnv1a24 => Fnv24, "fnv1a-24", 24, Fnv1a;
+    Fnv1a32 => Fnv32, "fnv1a-32", 32, Fnv1a;
+    Fnv1a48 => Fnv48, "fnv1a-48", 48, Fnv1a;
+    Fnv1a56 => Fnv56, "fnv1a-56", 56, Fnv1a;
+    Fnv1a64 => Fnv64, "fnv1a-64", 64, Fnv1a;
+    Fnv1a128 => Fnv128, "fnv1a-128", 128, Fnv1a;
+    Fnv1a256 => Fnv256, "fnv1a-256", 256, Fnv1a;
+    Fnv1a512 => Fnv512, "fnv1a-512", 512, Fnv1a;
+    Fnv1a1024 => Fnv1024, "fnv1a-1024", 1024, Fnv1a;
+    Fnv1_32 => Fnv1_32, "fnv1-32", 32, Fnv1;
+    Fnv1_64 => Fnv1_64, "fnv1-64", 64, Fnv1;
+    Fnv1_128 => Fnv1_128, "fnv1-128", 128, Fnv1;
+    Fnv1_256 => Fnv1_256, "fnv1-256", 256, Fnv1;
+    Fnv1_512 => Fnv1_512, "fnv1-512", 512, Fnv1;
+    Fnv1_1024 => Fnv1_1024, "fnv1-1024", 1024, Fnv1;
+    Fnv0_32 => Fnv0_32, "fnv0-32", 32, Fnv0;
+    Fnv0_64 => Fnv0_64, "fnv0-64", 64, Fnv0;
+    Fnv0_128 => Fnv0_128, "fnv0-128", 128, Fnv0;
+    Fnv0_256 => Fnv0_256, "fnv0-256", 256, Fnv0;
+    Fnv0_512 => Fnv0_512, "fnv0-512", 512, Fnv0;
+    Fnv0_1024 => Fnv0_1024, "fnv0-1024", 1024, Fnv0;
+}
+
+impl FnvAlgorithm {
+    /// The start of the multicodec private-use range, which FNV's multihash codes are taken from.
+    const MULTIHASH_PRIVATE_USE: u64 = 0x30_0000;
+
+    /// Returns this algorithm's multihash code.
+    ///
+    /// FNV has no registered multicodec, so the codes are this crate's own, in the private-use
+    /// range: `0x300000 | variant << 12 | bits`, where the variant is 0 for FNV-0, 1 for FNV-1
+    /// and 2 for FNV-1a. For example, `fnv1a-64` is `0x302040`.
+    pub const fn multihash_code(self) -> u64 {
+        let variant = match self.variant() {
+            FnvVariant::Fnv0 => 0,
+            FnvVariant::Fnv1 => 1,
+            FnvVariant::Fnv1a => 2,
+        };
+
+        FnvAlgorithm::MULTIHASH_PRIVATE_USE | variant << 12 | self.bits() as u64
+    }
+
+    /// Returns the algorithm with the multihash `code`, if any.
+    pub fn from_multihash_code(code: u64) -> Option<Self> {
+        FnvAlgorithm::ALL.iter().copied().find(|algorithm| algorithm.multihash_code() == code)
+    }
+}
+
+impl fmt::Display for FnvAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for FnvAlgorithm {
+    type Err = FnvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FnvAlgorithm::ALL
+            .iter()
+            .find(|algorithm| algorithm.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or(FnvError::UnknownAlgorithm)
+    }
+}
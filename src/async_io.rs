@@ -0,0 +1,281 @@
+//! Hashing data as it streams through tokio's `AsyncRead`/`AsyncWrite` and `futures` streams.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use std::io;
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::{FnvHasher, FnvHashResult};
+
+pin_project! {
+    /// An async reader that hashes every byte read through it.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher, HashingAsyncReader};
+    /// # use tokio::io::AsyncReadExt;
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut reader = HashingAsyncReader::<_, Fnv64>::new(&b"Hash this!"[..]);
+    /// let mut contents = Vec::new();
+    /// reader.read_to_end(&mut contents).await.unwrap();
+    /// assert_eq!(reader.finalize().as_bytes(), Fnv64::hash(b"Hash this!").as_bytes());
+    /// # });
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct HashingAsyncReader<R, H> {
+        #[pin]
+        inner: R,
+        hasher: H,
+    }
+}
+
+impl<R, H: FnvHasher> HashingAsyncReader<R, H> {
+    /// Wraps `inner`, hashing with a new `H`.
+    pub fn new(inner: R) -> Self {
+        HashingAsyncReader::with_hasher(inner, H::new())
+    }
+
+    /// Wraps `inner`, continuing from `hasher`.
+    pub fn with_hasher(inner: R, hasher: H) -> Self {
+        HashingAsyncReader { inner, hasher }
+    }
+
+    /// Returns the hash of the bytes read so far.
+    pub fn finalize(&self) -> FnvHashResult {
+        self.hasher.finalize().into()
+    }
+
+    /// Returns the hasher.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped reader.
+    ///
+    /// Bytes read directly from it are not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the reader and the hasher.
+    pub fn into_parts(self) -> (R, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<R: AsyncRead, H: FnvHasher> AsyncRead for HashingAsyncReader<R, H> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        let filled = buf.filled().len();
+
+        ready!(this.inner.poll_read(cx, buf))?;
+        this.hasher.update(&buf.filled()[filled..]);
+        Poll::Ready(Ok(()))
+    }
+}
+
+pin_project! {
+    /// An async writer that hashes every byte written through it.
+    ///
+    /// Only the bytes the wrapped writer accepts are hashed, so the hash always matches what was
+    /// written.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher, HashingAsyncWriter};
+    /// # use tokio::io::AsyncWriteExt;
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let mut writer = HashingAsyncWriter::<_, Fnv64>::new(Vec::new());
+    /// writer.write_all(b"Hash this!").await.unwrap();
+    /// writer.shutdown().await.unwrap();
+    /// assert_eq!(writer.finalize().as_bytes(), Fnv64::hash(b"Hash this!").as_bytes());
+    /// # });
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct HashingAsyncWriter<W, H> {
+        #[pin]
+        inner: W,
+        hasher: H,
+    }
+}
+
+impl<W, H: FnvHasher> HashingAsyncWriter<W, H> {
+    /// Wraps `inner`, hashing with a new `H`.
+    pub fn new(inner: W) -> Self {
+        HashingAsyncWriter::with_hasher(inner, H::new())
+    }
+
+    /// Wraps `inner`, continuing from `hasher`.
+    pub fn with_hasher(inner: W, hasher: H) -> Self {
+        HashingAsyncWriter { inner, hasher }
+    }
+
+    /// Returns the hash of the bytes written so far.
+    pub fn finalize(&self) -> FnvHashResult {
+        self.hasher.finalize().into()
+    }
+
+    /// Returns the hasher.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped writer.
+    ///
+    /// Bytes written directly to it are not hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the writer and the hasher.
+    pub fn into_parts(self) -> (W, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<W: AsyncWrite, H: FnvHasher> AsyncWrite for HashingAsyncWriter<W, H> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, bytes: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.project();
+
+        let written = ready!(this.inner.poll_write(cx, bytes))?;
+        this.hasher.update(&bytes[..written]);
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_shutdown(cx)
+    }
+}
+
+pin_project! {
+    /// A stream that hashes every chunk it yields, created by [`FnvStreamExt::fnv_hashing`].
+    #[derive(Clone, Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct HashingStream<S, H> {
+        #[pin]
+        inner: S,
+        hasher: H,
+    }
+}
+
+impl<S, H: FnvHasher> HashingStream<S, H> {
+    /// Returns the hash of the chunks yielded so far.
+    pub fn finalize(&self) -> FnvHashResult {
+        self.hasher.finalize().into()
+    }
+
+    /// Returns the hasher.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Unwraps the stream and the hasher.
+    pub fn into_parts(self) -> (S, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<S, H> Stream for HashingStream<S, H>
+where
+    S: Stream,
+    S::Item: AsRef<[u8]>,
+    H: FnvHasher,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        let this = self.project();
+
+        let chunk = ready!(this.inner.poll_next(cx));
+        if let Some(chunk) = &chunk {
+            this.hasher.update(chunk.as_ref());
+        }
+        Poll::Ready(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+pin_project! {
+    /// A future that hashes a whole stream, created by [`FnvStreamExt::fnv_hash`].
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct FnvHashFuture<S, H> {
+        #[pin]
+        inner: S,
+        hasher: H,
+    }
+}
+
+impl<S, H> Future for FnvHashFuture<S, H>
+where
+    S: Stream,
+    S::Item: AsRef<[u8]>,
+    H: FnvHasher,
+{
+    type Output = FnvHashResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<FnvHashResult> {
+        let mut this = self.project();
+
+        while let Some(chunk) = ready!(this.inner.as_mut().poll_next(cx)) {
+            this.hasher.update(chunk.as_ref());
+        }
+        Poll::Ready(this.hasher.finalize().into())
+    }
+}
+
+/// Hashing for streams of byte chunks, such as `Stream<Item = Bytes>`.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{Fnv64, FnvHasher, FnvStreamExt};
+/// # use futures_core::Stream;
+/// async fn checksum(upload: impl Stream<Item = bytes::Bytes>) -> fnv_rs::FnvHashResult {
+///     upload.fnv_hash::<Fnv64>().await
+/// }
+/// ```
+pub trait FnvStreamExt: Stream + Sized {
+    /// Wraps the stream, hashing each chunk as it passes through.
+    ///
+    /// Call [`HashingStream::finalize`] once the stream ends.
+    fn fnv_hashing<H: FnvHasher>(self) -> HashingStream<Self, H>
+    where
+        Self::Item: AsRef<[u8]>,
+    {
+        HashingStream { inner: self, hasher: H::new() }
+    }
+
+    /// Consumes the stream, resolving to the hash of all its chunks.
+    fn fnv_hash<H: FnvHasher>(self) -> FnvHashFuture<Self, H>
+    where
+        Self::Item: AsRef<[u8]>,
+    {
+        FnvHashFuture { inner: self, hasher: H::new() }
+    }
+}
+
+impl<S: Stream> FnvStreamExt for S {}
@@ -1,5 +1,4 @@
-#[cfg(feature = "bigint")]
-use crypto_bigint::{U256, U512, U1024};
+use crate::wide_uint::{SparsePrime, WideUint};
 
 // FNV Primes for primitive types
 pub const FNV_PRIME_32: u32 = 0x1000193;
@@ -8,16 +7,14 @@ pub const FNV_PRIME_128: u128 = 0x1000000_00000000_0000013B;
 
 /*  FNV Primes for larger numbers*/
 
-#[cfg(feature = "bigint")]
-pub const FNV_PRIME_256: U256 = U256::from_be_slice(&[
+pub const FNV_PRIME_256: WideUint<4> = WideUint::from_be_slice(&[
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x01u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x01u8, 0x63u8
 ]);
 
-#[cfg(feature = "bigint")]
-pub const FNV_PRIME_512: U512 = U512::from_be_slice(&[
+pub const FNV_PRIME_512: WideUint<8> = WideUint::from_be_slice(&[
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x01u8, 0x00u8, 0x00u8, 0x00u8,
@@ -28,8 +25,7 @@ pub const FNV_PRIME_512: U512 = U512::from_be_slice(&[
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x01u8, 0x57u8
 ]);
 
-#[cfg(feature = "bigint")]
-pub const FNV_PRIME_1024: U1024 = U1024::from_be_slice(&[
+pub const FNV_PRIME_1024: WideUint<16> = WideUint::from_be_slice(&[
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
@@ -48,6 +44,19 @@ pub const FNV_PRIME_1024: U1024 = U1024::from_be_slice(&[
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x01u8, 0x8Du8
 ]);
 
+/* The wide FNV primes split into `2^shift + low` for the hashers' multiplications */
+pub const FNV_PRIME_256_SPARSE: SparsePrime = SparsePrime::new(&FNV_PRIME_256);
+pub const FNV_PRIME_512_SPARSE: SparsePrime = SparsePrime::new(&FNV_PRIME_512);
+pub const FNV_PRIME_1024_SPARSE: SparsePrime = SparsePrime::new(&FNV_PRIME_1024);
+
+/* Inverses of the FNV primes modulo 2^bits, which undo a multiplication by the prime */
+pub const FNV_PRIME_INVERSE_32: u32 = 0x359C449B;
+pub const FNV_PRIME_INVERSE_64: u64 = 0xCE965057_AFF6957B;
+pub const FNV_PRIME_INVERSE_128: u128 = 0xB1041AD2_562FF2FF_2FF2FF2F_F2FF2FF3;
+pub const FNV_PRIME_INVERSE_256: WideUint<4> = FNV_PRIME_256.wrapping_inverse();
+pub const FNV_PRIME_INVERSE_512: WideUint<8> = FNV_PRIME_512.wrapping_inverse();
+pub const FNV_PRIME_INVERSE_1024: WideUint<16> = FNV_PRIME_1024.wrapping_inverse();
+
 
 /* FNV offset basis for primitive types */
 pub const FNV_OFFSET_32: u32 = 0x811C9DC5;
@@ -56,16 +65,14 @@ pub const FNV_OFFSET_128: u128 = 0x6C62272E_07BB0142_62B82175_6295C58D;
 
 /* FNV offset basis for larger numbers */
 
-#[cfg(feature = "bigint")]
-pub const FNV_OFFSET_256: U256 =  U256::from_be_slice(&[
+pub const FNV_OFFSET_256: WideUint<4> = WideUint::from_be_slice(&[
     0xDDu8, 0x26u8, 0x8Du8, 0xBCu8, 0xAAu8, 0xC5u8, 0x50u8, 0x36u8,
     0x2Du8, 0x98u8, 0xC3u8, 0x84u8, 0xC4u8, 0xE5u8, 0x76u8, 0xCCu8,
     0xC8u8, 0xB1u8, 0x53u8, 0x68u8, 0x47u8, 0xB6u8, 0xBBu8, 0xB3u8,
     0x10u8, 0x23u8, 0xB4u8, 0xC8u8, 0xCAu8, 0xEEu8, 0x05u8, 0x35u8
 ]);
 
-#[cfg(feature = "bigint")]
-pub const FNV_OFFSET_512: U512 = U512::from_be_slice(&[
+pub const FNV_OFFSET_512: WideUint<8> = WideUint::from_be_slice(&[
     0xB8u8, 0x6Du8, 0xB0u8, 0xB1u8, 0x17u8, 0x1Fu8, 0x44u8, 0x16u8,
     0xDCu8, 0xA1u8, 0xE5u8, 0x0Fu8, 0x30u8, 0x99u8, 0x90u8, 0xACu8,
     0xACu8, 0x87u8, 0xD0u8, 0x59u8, 0xC9u8, 0x00u8, 0x00u8, 0x00u8,
@@ -76,8 +83,7 @@ pub const FNV_OFFSET_512: U512 = U512::from_be_slice(&[
     0xACu8, 0x98u8, 0x2Au8, 0xACu8, 0x4Au8, 0xFEu8, 0x9Fu8, 0xD9u8
 ]);
 
-#[cfg(feature = "bigint")]
-pub const FNV_OFFSET_1024: U1024 = U1024::from_be_slice(&[
+pub const FNV_OFFSET_1024: WideUint<16> = WideUint::from_be_slice(&[
     0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8, 0x00u8,
     0x00u8, 0x5Fu8, 0x7Au8, 0x76u8, 0x75u8, 0x8Eu8, 0xCCu8, 0x4Du8,
     0x32u8, 0xE5u8, 0x6Du8, 0x5Au8, 0x59u8, 0x10u8, 0x28u8, 0xB7u8,
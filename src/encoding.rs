@@ -0,0 +1,180 @@
+//! Text encodings of hashes: RFC 4648 Base32 and Base64, Base58 and decimal.
+//!
+//! Base32 and Base64 pack whole bits into each character. Base58 and decimal treat the hash as
+//! a big-endian integer and convert its base with schoolbook division.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::hash_result::MAX_LEN;
+use crate::{FnvError, FnvHashResult};
+
+pub(crate) const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+pub(crate) const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+pub(crate) const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+pub(crate) const BASE58: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+pub(crate) const DECIMAL: &[u8; 10] = b"0123456789";
+
+/// The most digits a base conversion can produce: 309 decimal digits for a 1024-bit hash.
+#[cfg(feature = "alloc")]
+const MAX_DIGITS: usize = 309;
+
+/// Encodes `bytes` with `bits` bits per character, padding with `=` to a multiple of `group`.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_bits(bytes: &[u8], alphabet: &[u8], bits: u32, group: Option<usize>) -> String {
+    let mask = (1 << bits) - 1;
+    let mut out = String::with_capacity((bytes.len() * 8 + 7) / bits as usize + 8);
+
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    for byte in bytes {
+        acc = (acc << 8) | *byte as u32;
+        acc_bits += 8;
+        while acc_bits >= bits {
+            acc_bits -= bits;
+            out.push(alphabet[((acc >> acc_bits) & mask) as usize] as char);
+        }
+    }
+    if acc_bits > 0 {
+        out.push(alphabet[((acc << (bits - acc_bits)) & mask) as usize] as char);
+    }
+
+    if let Some(group) = group {
+        while out.len() % group != 0 {
+            out.push('=');
+        }
+    }
+
+    out
+}
+
+/// Decodes `s` with `bits` bits per character, where `=` padding up to a multiple of `group`
+/// is optional and any bits left over in the final character must be zero.
+pub(crate) fn decode_bits(
+    s: &str,
+    bits: u32,
+    group: usize,
+    decode: impl Fn(u8) -> Option<u8>,
+) -> Result<FnvHashResult, FnvError> {
+    let data = s.trim_end_matches('=');
+    let padded = data.len() != s.len();
+    if padded && s.len() != (data.len() + group - 1) / group * group {
+        return Err(FnvError::InvalidEncodedLength { len: s.len() });
+    }
+
+    // a final character that carries no whole byte means the input was cut short
+    let len = data.len() * bits as usize / 8;
+    if data.len() * bits as usize % 8 >= bits as usize {
+        return Err(FnvError::InvalidEncodedLength { len: s.len() });
+    }
+    if len == 0 || len > MAX_LEN {
+        return Err(FnvError::UnsupportedLength { len });
+    }
+
+    let mut bytes = [0u8; MAX_LEN];
+    let mut out = 0;
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    for (index, c) in data.char_indices() {
+        let value = u8::try_from(c).ok().and_then(&decode).ok_or(FnvError::InvalidCharacter { c, index })?;
+        acc = (acc << bits) | value as u32;
+        acc_bits += bits;
+        if acc_bits >= 8 {
+            acc_bits -= 8;
+            bytes[out] = (acc >> acc_bits) as u8;
+            out += 1;
+        }
+    }
+
+    // the leftover bits of the final character must be zero, so each hash has one encoding
+    if acc & ((1 << acc_bits) - 1) != 0 {
+        let (index, c) = data.char_indices().last().unwrap();
+        return Err(FnvError::InvalidCharacter { c, index });
+    }
+
+    Ok(FnvHashResult::from_slice(&bytes[..len]))
+}
+
+/// Converts the big-endian integer `bytes` to `base`, skipping leading zeros.
+#[cfg(feature = "alloc")]
+pub(crate) fn encode_base(bytes: &[u8], alphabet: &[u8]) -> String {
+    let base = alphabet.len() as u32;
+
+    // little-endian digits
+    let mut digits = [0u8; MAX_DIGITS];
+    let mut len = 0;
+    for byte in bytes {
+        let mut carry = *byte as u32;
+        for digit in &mut digits[..len] {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % base) as u8;
+            carry /= base;
+        }
+        while carry > 0 {
+            digits[len] = (carry % base) as u8;
+            len += 1;
+            carry /= base;
+        }
+    }
+
+    digits[..len].iter().rev().map(|digit| alphabet[*digit as usize] as char).collect()
+}
+
+/// Parses `s` in `base` into the big-endian integer `out`.
+///
+/// Returns the number of significant bytes, or `None` if the integer does not fit in `out`.
+pub(crate) fn decode_base(
+    s: &str,
+    base: u32,
+    decode: impl Fn(u8) -> Option<u8>,
+    out: &mut [u8],
+) -> Result<Option<usize>, FnvError> {
+    out.fill(0);
+    let mut len = 0;
+
+    for (index, c) in s.char_indices() {
+        let value = u8::try_from(c).ok().and_then(&decode).ok_or(FnvError::InvalidCharacter { c, index })?;
+
+        let mut carry = value as u32;
+        for byte in out.iter_mut().rev().take(len) {
+            carry += *byte as u32 * base;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            if len == out.len() {
+                return Ok(None);
+            }
+            len += 1;
+            let byte = &mut out[out.len() - len];
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+    }
+
+    Ok(Some(len))
+}
+
+fn position(alphabet: &[u8], c: u8) -> Option<u8> {
+    alphabet.iter().position(|digit| *digit == c).map(|value| value as u8)
+}
+
+pub(crate) fn decode_base32(c: u8) -> Option<u8> {
+    position(BASE32, c.to_ascii_uppercase())
+}
+
+pub(crate) fn decode_base64(c: u8) -> Option<u8> {
+    position(BASE64, c)
+}
+
+pub(crate) fn decode_base64url(c: u8) -> Option<u8> {
+    position(BASE64URL, c)
+}
+
+pub(crate) fn decode_base58(c: u8) -> Option<u8> {
+    position(BASE58, c)
+}
+
+pub(crate) fn decode_decimal(c: u8) -> Option<u8> {
+    position(DECIMAL, c)
+}
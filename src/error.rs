@@ -0,0 +1,60 @@
+use core::fmt;
+
+/// The error type for parsing and converting hashes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FnvError {
+    /// A character that is not a hex digit, at byte offset `index` of the input.
+    InvalidHexCharacter { c: char, index: usize },
+    /// Hex input with an odd number of digits.
+    OddLength,
+    /// A character outside the alphabet of a Base32, Base64, Base58 or decimal encoding, at byte
+    /// offset `index` of the input.
+    InvalidCharacter { c: char, index: usize },
+    /// Encoded input of `len` characters, which is cut short or wrongly padded.
+    InvalidEncodedLength { len: usize },
+    /// A number too large for a hash of `len` bytes.
+    Overflow { len: usize },
+    /// A hash of `len` bytes, where no hash can be empty or wider than FNV-1024.
+    UnsupportedLength { len: usize },
+    /// A `bits`-bit hash where one of the full FNV widths, 32 through 1024 bits, was required.
+    UnsupportedWidth { bits: usize },
+    /// A hash or state of `actual` bytes where `expected` bytes were required.
+    InvalidLength { expected: usize, actual: usize },
+    /// An unknown [`FnvAlgorithm`](crate::FnvAlgorithm) name or multihash code.
+    UnknownAlgorithm,
+    /// A multihash whose code or length prefix is cut short or malformed.
+    InvalidMultihash,
+}
+
+impl fmt::Display for FnvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FnvError::InvalidHexCharacter { c, index } => {
+                write!(f, "invalid hex character {:?} at index {}", c, index)
+            }
+            FnvError::OddLength => f.write_str("odd number of hex digits"),
+            FnvError::InvalidCharacter { c, index } => {
+                write!(f, "invalid character {:?} at index {}", c, index)
+            }
+            FnvError::InvalidEncodedLength { len } => {
+                write!(f, "{} characters is not a valid encoded length", len)
+            }
+            FnvError::Overflow { len } => write!(f, "number does not fit in {} bytes", len),
+            FnvError::UnsupportedLength { len } => {
+                write!(f, "a hash must be 1 to 128 bytes long, not {} bytes", len)
+            }
+            FnvError::UnsupportedWidth { bits } => {
+                write!(f, "expected a full FNV width, found a {}-bit hash", bits)
+            }
+            FnvError::InvalidLength { expected, actual } => {
+                write!(f, "expected {} bytes, found {} bytes", expected, actual)
+            }
+            FnvError::UnknownAlgorithm => f.write_str("unknown FNV algorithm"),
+            FnvError::InvalidMultihash => f.write_str("malformed multihash prefix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FnvError {}
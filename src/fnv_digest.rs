@@ -0,0 +1,181 @@
+use core::fmt;
+use crate::{FnvError, FnvHashResult};
+
+#[cfg(feature = "bigint")]
+use crypto_bigint::{Encoding, U256, U512, U1024};
+
+/// A digest whose width is known at compile time.
+///
+/// Every hasher returns an `FnvDigest<N>` holding its `N`-byte, big-endian output, e.g.
+/// [`Fnv64`](crate::Fnv64) returns an `FnvDigest<8>`. Digests of different widths are different
+/// types, so they cannot be mixed up. Convert one into an [`FnvHashResult`] when the width is
+/// only known at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FnvDigest<const N: usize>(pub(crate) [u8; N]);
+
+impl<const N: usize> FnvDigest<N> {
+    /// Returns a reference to this `FnvDigest`'s bytes.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let hash = Fnv64::hash(bytes);
+    /// let hash_bytes: &[u8; 8] = hash.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns this `FnvDigest`'s bytes.
+    #[inline]
+    pub fn to_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Returns an all-caps hex `String` of this `FnvDigest`'s contents.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let hash = Fnv64::hash(bytes);
+    /// let hash_hex = hash.as_hex();
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn as_hex(&self) -> alloc::string::String {
+        hex::encode_upper(self.0)
+    }
+
+    /// Returns the length of this `FnvDigest` in bytes, which is always `N`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if this `FnvDigest` holds no bytes.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Xor-folds this `FnvDigest` down to a `bits`-bit hash.
+    ///
+    /// See [`FnvHashResult::xor_fold`].
+    pub fn xor_fold(&self, bits: u32) -> FnvHashResult {
+        self.to_hash_result().xor_fold(bits)
+    }
+
+    /// Maps this `FnvDigest` onto `0..range` using the FNV spec's "lazy mod mapping".
+    ///
+    /// See [`FnvHashResult::lazy_mod`].
+    pub fn lazy_mod(&self, range: u64) -> u64 {
+        self.to_hash_result().lazy_mod(range)
+    }
+
+    /// Maps this `FnvDigest` onto `0..range` using the FNV spec's "retry method".
+    ///
+    /// See [`FnvHashResult::retry_mod`].
+    pub fn retry_mod(&self, range: u64) -> Result<u64, FnvError> {
+        self.to_hash_result().retry_mod(range)
+    }
+
+    /// Every `FnvDigest` is built by a hasher or from an `FnvHashResult`, so `N` is always a
+    /// valid hash length.
+    pub(crate) fn to_hash_result(self) -> FnvHashResult {
+        FnvHashResult::from_slice(&self.0)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FnvDigest<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Conversions for the widths the hashers produce; other widths go through `TryFrom`.
+macro_rules! impl_digest_conversions {
+    ($($n:literal),+) => {$(
+        impl From<[u8; $n]> for FnvDigest<$n> {
+            fn from(bytes: [u8; $n]) -> Self {
+                FnvDigest(bytes)
+            }
+        }
+
+        impl From<FnvDigest<$n>> for FnvHashResult {
+            fn from(digest: FnvDigest<$n>) -> Self {
+                digest.to_hash_result()
+            }
+        }
+    )+};
+}
+
+impl_digest_conversions!(2, 3, 4, 6, 7, 8, 16, 32, 64, 128);
+
+impl From<FnvDigest<4>> for u32 {
+    fn from(digest: FnvDigest<4>) -> Self {
+        u32::from_be_bytes(digest.0)
+    }
+}
+
+impl From<FnvDigest<8>> for u64 {
+    fn from(digest: FnvDigest<8>) -> Self {
+        u64::from_be_bytes(digest.0)
+    }
+}
+
+impl From<FnvDigest<16>> for u128 {
+    fn from(digest: FnvDigest<16>) -> Self {
+        u128::from_be_bytes(digest.0)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<FnvDigest<32>> for U256 {
+    fn from(digest: FnvDigest<32>) -> Self {
+        U256::from_be_bytes(digest.0)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<FnvDigest<64>> for U512 {
+    fn from(digest: FnvDigest<64>) -> Self {
+        U512::from_be_bytes(digest.0)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<FnvDigest<128>> for U1024 {
+    fn from(digest: FnvDigest<128>) -> Self {
+        U1024::from_be_bytes(digest.0)
+    }
+}
+
+impl<const N: usize> fmt::Display for FnvDigest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for FnvDigest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for FnvDigest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02X}", byte))
+    }
+}
+
+impl<const N: usize> fmt::Debug for FnvDigest<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("FnvDigest")
+            .field(&format_args!("{:X}", self))
+            .finish()
+    }
+}
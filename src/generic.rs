@@ -0,0 +1,369 @@
+//! The generic FNV hasher, parameterized over its prime, offset basis, width and variant.
+//!
+//! Every built-in hasher is a preset of [`Fnv`], e.g. [`Fnv64`](crate::Fnv64) is
+//! `Fnv<Fnv64Params>`. Implement [`FnvParams`] to hash with non-standard parameters.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::{FnvDigest, FnvHasher, FnvHashResult, WideUint};
+
+/// The order of the xor and multiply steps, and the starting value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FnvVariant {
+    /// FNV-0: multiply, then xor, starting from zero. The offset basis is ignored.
+    Fnv0,
+    /// FNV-1: multiply, then xor, starting from the offset basis.
+    Fnv1,
+    /// FNV-1a: xor, then multiply, starting from the offset basis.
+    Fnv1a,
+}
+
+/// An unsigned integer an FNV hash can be computed in.
+///
+/// Implemented for `u32`, `u64`, `u128` and the 256, 512 & 1024-bit [`WideUint`]s.
+pub trait FnvWord: Copy + Eq + fmt::Debug {
+    /// The digest of a hash of this width, e.g. `FnvDigest<8>` for `u64`.
+    type Digest: Into<FnvHashResult>;
+
+    const ZERO: Self;
+
+    /// Xors `byte` into the lowest 8 bits.
+    fn xor_byte(self, byte: u8) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    /// Returns the inverse of `self` modulo `2^bits`.
+    ///
+    /// Panics if `self` is even, as only odd numbers have an inverse.
+    fn wrapping_inverse(self) -> Self;
+    /// Converts the final hash into its big-endian digest.
+    fn to_digest(self) -> Self::Digest;
+    /// Reads a hash back from its big-endian digest.
+    fn from_digest(digest: Self::Digest) -> Self;
+}
+
+macro_rules! impl_fnv_word {
+    ($($word:ty: $bytes:literal),+) => {$(
+        impl FnvWord for $word {
+            type Digest = FnvDigest<$bytes>;
+
+            const ZERO: Self = 0;
+
+            #[inline]
+            fn xor_byte(self, byte: u8) -> Self {
+                self ^ byte as $word
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$word>::wrapping_mul(self, rhs)
+            }
+
+            fn wrapping_inverse(self) -> Self {
+                assert!(self % 2 == 1, "only odd numbers have an inverse");
+
+                // Newton's iteration, doubling the correct low bits from 3 each step
+                let mut inverse = self;
+                for _ in 0..<$word>::BITS.trailing_zeros() - 1 {
+                    inverse = inverse.wrapping_mul((2 as $word).wrapping_sub(self.wrapping_mul(inverse)));
+                }
+
+                inverse
+            }
+
+            #[inline]
+            fn to_digest(self) -> FnvDigest<$bytes> {
+                FnvDigest(self.to_be_bytes())
+            }
+
+            #[inline]
+            fn from_digest(digest: FnvDigest<$bytes>) -> Self {
+                <$word>::from_be_bytes(digest.0)
+            }
+        }
+    )+};
+}
+
+impl_fnv_word!(u32: 4, u64: 8, u128: 16);
+
+macro_rules! impl_fnv_word_wide {
+    ($($limbs:literal: $bytes:literal),+) => {$(
+        impl FnvWord for WideUint<$limbs> {
+            type Digest = FnvDigest<$bytes>;
+
+            const ZERO: Self = WideUint::ZERO;
+
+            #[inline]
+            fn xor_byte(self, byte: u8) -> Self {
+                WideUint::xor_byte(self, byte)
+            }
+
+            #[inline]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                WideUint::wrapping_mul(&self, &rhs)
+            }
+
+            fn wrapping_inverse(self) -> Self {
+                WideUint::wrapping_inverse(&self)
+            }
+
+            #[inline]
+            fn to_digest(self) -> FnvDigest<$bytes> {
+                FnvDigest(self.to_be_bytes())
+            }
+
+            #[inline]
+            fn from_digest(digest: FnvDigest<$bytes>) -> Self {
+                WideUint::from_be_slice(&digest.0)
+            }
+        }
+    )+};
+}
+
+impl_fnv_word_wide!(4: 32, 8: 64, 16: 128);
+
+/// The parameters of an FNV hash: its width, prime, offset basis and variant.
+///
+/// # Example:
+///
+/// ```
+/// use fnv_rs::{Fnv, FnvHasher, FnvParams, FnvVariant};
+///
+/// /// FNV-1a with a custom offset basis.
+/// struct Custom32;
+///
+/// impl FnvParams for Custom32 {
+///     type Word = u32;
+///     const PRIME: u32 = 0x01000193;
+///     const OFFSET_BASIS: u32 = 0x12345678;
+///     const VARIANT: FnvVariant = FnvVariant::Fnv1a;
+/// }
+///
+/// let hash = Fnv::<Custom32>::hash(b"Hash this!");
+/// ```
+pub trait FnvParams {
+    /// The integer the hash is computed in, which sets its width.
+    type Word: FnvWord;
+
+    const PRIME: Self::Word;
+    const OFFSET_BASIS: Self::Word;
+    const VARIANT: FnvVariant;
+
+    /// Multiplies `hash` by [`PRIME`](FnvParams::PRIME).
+    ///
+    /// Override this when the prime allows a faster multiplication.
+    #[inline]
+    fn multiply(hash: Self::Word) -> Self::Word {
+        hash.wrapping_mul(Self::PRIME)
+    }
+
+    /// Multiplies `hash` by the inverse of [`PRIME`](FnvParams::PRIME) modulo `2^bits`, undoing
+    /// [`multiply`](FnvParams::multiply).
+    ///
+    /// The default computes the inverse on every call. Override this with a precomputed inverse
+    /// to make [`Fnv::unupdate`] faster.
+    ///
+    /// Panics if `PRIME` is even, as only odd primes can be undone.
+    #[inline]
+    fn unmultiply(hash: Self::Word) -> Self::Word {
+        hash.wrapping_mul(Self::PRIME.wrapping_inverse())
+    }
+}
+
+/// An FNV hasher over the parameters `P`.
+///
+/// See [`FnvParams`] for hashing with custom parameters.
+pub struct Fnv<P: FnvParams> {
+    pub(crate) hash: P::Word,
+    params: PhantomData<P>,
+}
+
+impl<P: FnvParams> Fnv<P> {
+    /// The state a fresh hasher starts from.
+    const START: P::Word = match P::VARIANT {
+        FnvVariant::Fnv0 => P::Word::ZERO,
+        FnvVariant::Fnv1 | FnvVariant::Fnv1a => P::OFFSET_BASIS,
+    };
+
+    #[inline]
+    pub(crate) const fn from_word(hash: P::Word) -> Self {
+        Fnv { hash, params: PhantomData }
+    }
+
+    /// Exports the running hash, to resume hashing later with [`from_state`](Fnv::from_state).
+    ///
+    /// The state is encoded like a digest: the running hash as big-endian bytes, e.g. 8 bytes
+    /// for [`Fnv64`](crate::Fnv64). This encoding is stable across versions.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvDigest, FnvHasher};
+    /// let mut hasher = Fnv64::new();
+    /// hasher.update(b"Hash ");
+    /// let saved: [u8; 8] = hasher.state().to_bytes();
+    ///
+    /// let mut resumed = Fnv64::from_state(FnvDigest::from(saved));
+    /// resumed.update(b"this!");
+    /// assert_eq!(resumed.finalize(), Fnv64::hash(b"Hash this!"));
+    /// ```
+    #[inline]
+    pub fn state(&self) -> <P::Word as FnvWord>::Digest {
+        self.hash.to_digest()
+    }
+
+    /// Resumes hashing from a state exported by [`state`](Fnv::state).
+    #[inline]
+    pub fn from_state(state: <P::Word as FnvWord>::Digest) -> Self {
+        Fnv::from_word(P::Word::from_digest(state))
+    }
+
+    /// Removes `bytes` from the end of the hashed data, undoing [`update`](FnvHasher::update).
+    ///
+    /// `bytes` must be the last bytes the hasher was updated with, otherwise the state becomes
+    /// garbage. This lets a running hash drop trailing bytes without rehashing from the start.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// let mut hasher = Fnv64::new();
+    /// hasher.update(b"Hash this!");
+    /// hasher.unupdate(b"this!");
+    /// assert_eq!(hasher.finalize(), Fnv64::hash(b"Hash "));
+    /// ```
+    #[inline]
+    pub fn unupdate(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes.iter().rev() {
+            hash = match P::VARIANT {
+                FnvVariant::Fnv1a => P::unmultiply(hash).xor_byte(*byte),
+                FnvVariant::Fnv0 | FnvVariant::Fnv1 => P::unmultiply(hash.xor_byte(*byte)),
+            };
+        }
+
+        self.hash = hash;
+    }
+
+    #[inline(always)]
+    fn step(hash: P::Word, byte: u8) -> P::Word {
+        match P::VARIANT {
+            FnvVariant::Fnv1a => P::multiply(hash.xor_byte(byte)),
+            FnvVariant::Fnv0 | FnvVariant::Fnv1 => P::multiply(hash).xor_byte(byte),
+        }
+    }
+}
+
+impl<P: FnvParams> Default for Fnv<P> {
+    #[inline]
+    fn default() -> Self {
+        Fnv::from_word(Self::START)
+    }
+}
+
+// Implemented by hand, as derives would needlessly require `P` itself to be `Clone`, `Eq`, etc.
+impl<P: FnvParams> Clone for Fnv<P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: FnvParams> Copy for Fnv<P> {}
+
+impl<P: FnvParams> PartialEq for Fnv<P> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl<P: FnvParams> Eq for Fnv<P> {}
+
+impl<P: FnvParams> fmt::Debug for Fnv<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Fnv").field(&self.hash).finish()
+    }
+}
+
+impl<P: FnvParams> FnvHasher for Fnv<P> {
+    type Output = <P::Word as FnvWord>::Digest;
+
+    /// Creates a new hasher, starting from the offset basis.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// let hasher = Fnv64::new();
+    /// ```
+    #[inline]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Incrementally update the hasher.
+    ///
+    /// This method is best used when you need to update the hasher multiple times.
+    /// If you only need to hash a single piece of data, consider using the [`hash`] method instead.
+    ///
+    /// [`hash`]: Fnv::hash
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let mut hasher = Fnv64::new();
+    /// # let bytes = b"Hash this!";
+    /// hasher.update(bytes);
+    /// ```
+    #[inline]
+    fn update(&mut self, bytes: &[u8]) {
+        let mut hash = self.hash;
+
+        for byte in bytes.iter() {
+            hash = Self::step(hash, *byte);
+        }
+
+        self.hash = hash;
+    }
+
+    /// Finalize the hasher.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let hasher = Fnv64::new();
+    /// hasher.finalize();
+    /// ```
+    #[inline]
+    fn finalize(&self) -> Self::Output {
+        self.hash.to_digest()
+    }
+
+    /// One-time use of the hasher.
+    ///
+    /// Using this method is shorthand for the following:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let mut hasher = Fnv64::new();
+    /// hasher.update(bytes);
+    /// hasher.finalize();
+    /// ```
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let hash = Fnv64::hash(bytes);
+    /// ```
+    #[inline]
+    fn hash<T: AsRef<[u8]>>(bytes: T) -> Self::Output {
+        let mut hasher = Self::new();
+        hasher.update(bytes.as_ref());
+        hasher.finalize()
+    }
+}
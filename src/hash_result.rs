@@ -1,28 +1,45 @@
-use std::fmt;
-use hex;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use paste::paste;
+use crate::{consts, encoding, range, FnvDigest, FnvError};
+use crate::wide_uint::WideUint;
 
-/// A specialized result type for FNV operations.
-pub struct FnvHashResult(Box<[u8]>);
-
-impl FnvHashResult {
-    pub(crate) fn from_u32(i: u32) -> Self {
-        FnvHashResult(Box::from(i.to_be_bytes()))
-    }
+#[cfg(feature = "bigint")]
+use crypto_bigint::{U256, U512, U1024};
 
-    pub(crate) fn from_u64(i: u64) -> Self {
-        FnvHashResult(Box::from(i.to_be_bytes()))
-    }
+/// The largest digest an `FnvHashResult` can hold, in bytes (FNV-1024).
+pub(crate) const MAX_LEN: usize = 128;
 
-    pub(crate) fn from_u128(i: u128) -> Self {
-        FnvHashResult(Box::from(i.to_be_bytes()))
-    }
+/// A specialized result type for FNV operations.
+///
+/// Unlike [`FnvDigest`](crate::FnvDigest), the width of an `FnvHashResult` is only known at
+/// runtime. The digest is stored inline, so producing an `FnvHashResult` never allocates.
+///
+/// Hashes compare by their bytes, so hashes of the same width order numerically. A hash parses
+/// from hex, in upper or lower case, with an optional `0x` prefix.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{Fnv64, FnvHasher, FnvHashResult};
+/// let hash: FnvHashResult = "0x0B9712E03AC3958E".parse().unwrap();
+/// assert_eq!(hash, Fnv64::hash(b"Hash this!").into());
+/// assert_eq!(hash, "0b9712e03ac3958e".parse().unwrap());
+/// ```
+#[derive(Clone, Copy)]
+pub struct FnvHashResult {
+    bytes: [u8; MAX_LEN],
+    len: usize,
+}
 
-    #[cfg(feature = "bigint")]
-    pub(crate) fn from_bigint<T>(bigint: T) -> Self
-    where
-        T: crypto_bigint::prelude::Encoding,
-    {
-        FnvHashResult(Box::from(bigint.to_be_bytes().as_ref()))
+impl FnvHashResult {
+    #[inline]
+    pub(crate) fn from_slice(slice: &[u8]) -> Self {
+        let mut bytes = [0u8; MAX_LEN];
+        bytes[..slice.len()].copy_from_slice(slice);
+        FnvHashResult { bytes, len: slice.len() }
     }
 
     /// Returns a byte slice of this `FnvHashResult`'s contents.
@@ -30,11 +47,13 @@ impl FnvHashResult {
     /// # Example:
     ///
     /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
     /// let hash = Fnv64::hash(bytes);
     /// let hash_bytes = hash.as_bytes();
     /// ```
     pub fn as_bytes(&self) -> &[u8] {
-        &self.0
+        &self.bytes[..self.len]
     }
 
     /// Returns an all-caps hex `String` of this `FnvHashResult`'s contents.
@@ -42,11 +61,135 @@ impl FnvHashResult {
     /// # Example:
     ///
     /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
     /// let hash = Fnv64::hash(bytes);
     /// let hash_hex = hash.as_hex();
     /// ```
-    pub fn as_hex(&self) -> String {
-        hex::encode(&self.0).to_uppercase()
+    #[cfg(feature = "alloc")]
+    pub fn as_hex(&self) -> alloc::string::String {
+        hex::encode_upper(self.as_bytes())
+    }
+
+    /// Returns this `FnvHashResult` in RFC 4648 Base32, padded with `=`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher, FnvHashResult};
+    /// let hash: FnvHashResult = Fnv64::hash(b"Hash this!").into();
+    /// assert_eq!(hash.to_base32(), "BOLRFYB2YOKY4===");
+    /// assert_eq!(FnvHashResult::from_base32("BOLRFYB2YOKY4===").unwrap(), hash);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_base32(&self) -> alloc::string::String {
+        encoding::encode_bits(self.as_bytes(), encoding::BASE32, 5, Some(8))
+    }
+
+    /// Returns this `FnvHashResult` in RFC 4648 Base32, without padding.
+    #[cfg(feature = "alloc")]
+    pub fn to_base32_unpadded(&self) -> alloc::string::String {
+        encoding::encode_bits(self.as_bytes(), encoding::BASE32, 5, None)
+    }
+
+    /// Parses RFC 4648 Base32, in upper or lower case and with or without padding.
+    pub fn from_base32(s: &str) -> Result<Self, FnvError> {
+        encoding::decode_bits(s, 5, 8, encoding::decode_base32)
+    }
+
+    /// Returns this `FnvHashResult` in URL-safe Base64 (RFC 4648 `base64url`), without padding.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher, FnvHashResult};
+    /// let hash: FnvHashResult = Fnv64::hash(b"Hash this!").into();
+    /// assert_eq!(hash.to_base64url(), "C5cS4DrDlY4");
+    /// assert_eq!(FnvHashResult::from_base64url("C5cS4DrDlY4").unwrap(), hash);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_base64url(&self) -> alloc::string::String {
+        encoding::encode_bits(self.as_bytes(), encoding::BASE64URL, 6, None)
+    }
+
+    /// Parses URL-safe Base64, with or without padding.
+    pub fn from_base64url(s: &str) -> Result<Self, FnvError> {
+        encoding::decode_bits(s, 6, 4, encoding::decode_base64url)
+    }
+
+    /// Returns this `FnvHashResult` in Base58, with the Bitcoin alphabet.
+    ///
+    /// Each leading zero byte is written as a `1`, so the width survives a round trip.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher, FnvHashResult};
+    /// let hash: FnvHashResult = Fnv64::hash(b"Hash this!").into();
+    /// assert_eq!(hash.to_base58(), "2wST7UrkqCh");
+    /// assert_eq!(FnvHashResult::from_base58("2wST7UrkqCh").unwrap(), hash);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_base58(&self) -> alloc::string::String {
+        let bytes = self.as_bytes();
+        let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+
+        let mut out = "1".repeat(zeros);
+        out.push_str(&encoding::encode_base(&bytes[zeros..], encoding::BASE58));
+        out
+    }
+
+    /// Parses Base58 with the Bitcoin alphabet.
+    pub fn from_base58(s: &str) -> Result<Self, FnvError> {
+        let zeros = s.bytes().take_while(|c| *c == b'1').count();
+
+        let mut value = [0u8; MAX_LEN];
+        let len = encoding::decode_base(s, 58, encoding::decode_base58, &mut value)?
+            .ok_or(FnvError::Overflow { len: MAX_LEN })?;
+        if zeros + len == 0 || zeros + len > MAX_LEN {
+            return Err(FnvError::UnsupportedLength { len: zeros + len });
+        }
+
+        let mut bytes = [0u8; MAX_LEN];
+        bytes[zeros..zeros + len].copy_from_slice(&value[MAX_LEN - len..]);
+        Ok(FnvHashResult { bytes, len: zeros + len })
+    }
+
+    /// Returns this `FnvHashResult` as a decimal integer.
+    ///
+    /// Decimal drops leading zeros, so pass the width back to [`from_decimal`].
+    ///
+    /// [`from_decimal`]: FnvHashResult::from_decimal
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher, FnvHashResult};
+    /// let hash: FnvHashResult = Fnv64::hash(b"Hash this!").into();
+    /// assert_eq!(hash.to_decimal(), "835157010168386958");
+    /// assert_eq!(FnvHashResult::from_decimal("835157010168386958", 8).unwrap(), hash);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_decimal(&self) -> alloc::string::String {
+        let decimal = encoding::encode_base(self.as_bytes(), encoding::DECIMAL);
+        if decimal.is_empty() { "0".into() } else { decimal }
+    }
+
+    /// Parses a decimal integer into a hash of `len` bytes.
+    ///
+    /// Fails with [`FnvError::Overflow`] if the number does not fit in `len` bytes.
+    pub fn from_decimal(s: &str, len: usize) -> Result<Self, FnvError> {
+        if len == 0 || len > MAX_LEN {
+            return Err(FnvError::UnsupportedLength { len });
+        }
+        if s.is_empty() {
+            return Err(FnvError::InvalidEncodedLength { len: 0 });
+        }
+
+        let mut bytes = [0u8; MAX_LEN];
+        encoding::decode_base(s, 10, encoding::decode_decimal, &mut bytes[..len])?
+            .ok_or(FnvError::Overflow { len })?;
+        Ok(FnvHashResult { bytes, len })
     }
 
     /// Returns the length of this `FnvHashResult`'s underlying byte slice.
@@ -54,36 +197,271 @@ impl FnvHashResult {
     /// # Example:
     ///
     /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// # let bytes = b"Hash this!";
     /// let hash = Fnv64::hash(bytes);
     /// let hash_byte_count = hash.len();
     /// ```
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.len
+    }
+
+    /// Returns `true` if this `FnvHashResult`'s underlying byte slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Xor-folds this `FnvHashResult` down to a `bits`-bit hash.
+    ///
+    /// This computes `((hash >> bits) ^ hash) & mask` as described in the FNV spec. The result is
+    /// stored big-endian in `ceil(bits / 8)` bytes, with any unused high bits set to zero. For
+    /// the best distribution, fold from the smallest FNV width that is at least `bits` wide.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `bits` is zero or larger than the width of this `FnvHashResult`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv32, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let hash = Fnv32::hash(bytes);
+    /// let hash_24 = hash.xor_fold(24);
+    /// assert_eq!(hash_24.len(), 3);
+    /// ```
+    pub fn xor_fold(&self, bits: u32) -> Self {
+        let hash = self.as_bytes();
+        let width = hash.len() * 8;
+        let bits = bits as usize;
+        assert!(bits > 0 && bits <= width, "cannot fold a {}-bit hash to {} bits", width, bits);
+
+        let out_len = (bits + 7) / 8;
+        let byte_shift = bits / 8;
+        let bit_shift = bits % 8;
+
+        // byte `i` counted from the least significant end, zero past the most significant byte
+        let byte_at = |i: usize| -> u8 {
+            if i < hash.len() { hash[hash.len() - 1 - i] } else { 0 }
+        };
+
+        let mut folded = [0u8; MAX_LEN];
+        let folded = &mut folded[..out_len];
+        for (i, out) in folded.iter_mut().rev().enumerate() {
+            let mut high = byte_at(byte_shift + i) >> bit_shift;
+            if bit_shift > 0 {
+                high |= byte_at(byte_shift + i + 1) << (8 - bit_shift);
+            }
+            *out = high ^ byte_at(i);
+        }
+
+        if bit_shift > 0 {
+            folded[0] &= 0xFF >> (8 - bit_shift);
+        }
+
+        FnvHashResult::from_slice(folded)
+    }
+
+    /// Maps this `FnvHashResult` onto `0..range` using the FNV spec's "lazy mod mapping".
+    ///
+    /// This is simply the hash modulo `range`. It is slightly biased towards the low end of the
+    /// range, which is negligible when `range` is much smaller than the hash. Use [`retry_mod`]
+    /// when the bias matters.
+    ///
+    /// [`retry_mod`]: FnvHashResult::retry_mod
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `range` is zero.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv32, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let hash = Fnv32::hash(bytes);
+    /// let bucket = hash.lazy_mod(1000);
+    /// assert!(bucket < 1000);
+    /// ```
+    pub fn lazy_mod(&self, range: u64) -> u64 {
+        range::lazy_mod_bytes(self.as_bytes(), range)
+    }
+
+    /// Maps this `FnvHashResult` onto `0..range` using the FNV spec's "retry method".
+    ///
+    /// While the hash falls into the final, partial multiple of `range`, it is re-mixed with
+    /// `hash * FNV_PRIME + FNV_OFFSET` for its width. The final modulo is then unbiased.
+    ///
+    /// The re-mixing is only defined for the full FNV widths, so a hash of any other width (e.g.
+    /// from [`Fnv24`](crate::Fnv24) or [`xor_fold`](FnvHashResult::xor_fold)) fails with
+    /// [`FnvError::UnsupportedWidth`].
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `range` is zero.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv32, FnvHasher};
+    /// # let bytes = b"Hash this!";
+    /// let hash = Fnv32::hash(bytes);
+    /// let bucket = hash.retry_mod(1000).unwrap();
+    /// assert!(bucket < 1000);
+    /// ```
+    pub fn retry_mod(&self, range: u64) -> Result<u64, FnvError> {
+        let hash = self.as_bytes();
+        let bucket = match hash.len() {
+            4 => range::retry_u32(u32::from_be_bytes(hash.try_into().unwrap()), range),
+            8 => range::retry_u64(u64::from_be_bytes(hash.try_into().unwrap()), range),
+            16 => range::retry_u128(u128::from_be_bytes(hash.try_into().unwrap()), range),
+            32 => range::retry_wide(
+                WideUint::<4>::from_be_slice(hash), &consts::FNV_PRIME_256, &consts::FNV_OFFSET_256, range
+            ),
+            64 => range::retry_wide(
+                WideUint::<8>::from_be_slice(hash), &consts::FNV_PRIME_512, &consts::FNV_OFFSET_512, range
+            ),
+            128 => range::retry_wide(
+                WideUint::<16>::from_be_slice(hash), &consts::FNV_PRIME_1024, &consts::FNV_OFFSET_1024, range
+            ),
+            len => return Err(FnvError::UnsupportedWidth { bits: len * 8 }),
+        };
+
+        Ok(bucket)
+    }
+}
+
+impl PartialEq for FnvHashResult {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for FnvHashResult {}
+
+impl PartialOrd for FnvHashResult {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FnvHashResult {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl Hash for FnvHashResult {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl AsRef<[u8]> for FnvHashResult {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl FromStr for FnvHashResult {
+    type Err = FnvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (prefix_len, digits) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(digits) => (2, digits),
+            None => (0, s),
+        };
+
+        if digits.len() % 2 != 0 {
+            return Err(FnvError::OddLength);
+        }
+        let len = digits.len() / 2;
+        if len == 0 || len > MAX_LEN {
+            return Err(FnvError::UnsupportedLength { len });
+        }
+
+        let mut bytes = [0u8; MAX_LEN];
+        hex::decode_to_slice(digits, &mut bytes[..len]).map_err(|err| match err {
+            hex::FromHexError::InvalidHexCharacter { c, index } => {
+                FnvError::InvalidHexCharacter { c, index: prefix_len + index }
+            }
+            hex::FromHexError::OddLength | hex::FromHexError::InvalidStringLength => {
+                unreachable!("the length was checked above")
+            }
+        })?;
+
+        Ok(FnvHashResult { bytes, len })
+    }
+}
+
+/// Converts a digest back, failing with [`FnvError::InvalidLength`] if it is not `N` bytes wide.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{Fnv64, FnvDigest, FnvHasher, FnvHashResult};
+/// let hash: FnvHashResult = Fnv64::hash(b"Hash this!").into();
+/// let digest = FnvDigest::<8>::try_from(hash).unwrap();
+/// assert!(FnvDigest::<4>::try_from(hash).is_err());
+/// ```
+impl<const N: usize> TryFrom<FnvHashResult> for FnvDigest<N> {
+    type Error = FnvError;
+
+    fn try_from(hash: FnvHashResult) -> Result<Self, Self::Error> {
+        hash.as_bytes()
+            .try_into()
+            .map(FnvDigest)
+            .map_err(|_| FnvError::InvalidLength { expected: N, actual: hash.len() })
     }
 }
 
+macro_rules! impl_int_conversions {
+    ($($int:ident: $bytes:literal),+) => { paste! {$(
+        #[doc = "Converts a `" $bytes "`-byte hash into a `" $int "`, failing with [`FnvError::InvalidLength`] for any other width."]
+        impl TryFrom<FnvHashResult> for $int {
+            type Error = FnvError;
+
+            fn try_from(hash: FnvHashResult) -> Result<Self, Self::Error> {
+                FnvDigest::<$bytes>::try_from(hash).map(<$int>::from)
+            }
+        }
+
+        #[doc = "Stores a `" $int "` as a " $bytes "-byte, big-endian hash."]
+        impl From<$int> for FnvHashResult {
+            fn from(int: $int) -> Self {
+                FnvHashResult::from_slice(&int.to_be_bytes())
+            }
+        }
+    )+}};
+}
+
+impl_int_conversions!(u32: 4, u64: 8, u128: 16);
+
+#[cfg(feature = "bigint")]
+impl_int_conversions!(U256: 32, U512: 64, U1024: 128);
+
 impl fmt::Display for FnvHashResult {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.as_hex())
+        fmt::UpperHex::fmt(self, f)
     }
 }
 
 impl fmt::LowerHex for FnvHashResult {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", hex::encode(&self.0))
+        self.as_bytes().iter().try_for_each(|byte| write!(f, "{:02x}", byte))
     }
 }
 
 impl fmt::UpperHex for FnvHashResult {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.as_hex())
+        self.as_bytes().iter().try_for_each(|byte| write!(f, "{:02X}", byte))
     }
 }
 
 impl fmt::Debug for FnvHashResult {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_tuple("FnvHashResult")
-            .field(&self.as_hex())
+            .field(&format_args!("{:X}", self))
             .finish()
     }
 }
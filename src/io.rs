@@ -0,0 +1,185 @@
+//! Hashing data as it streams through `std::io`.
+
+use std::io::{self, Read, Write};
+
+use crate::{DynFnvHasher, Fnv, FnvHasher, FnvHashResult, FnvParams};
+use crate::{Fnv16, Fnv24, Fnv48, Fnv56};
+
+/// Writing to a hasher updates it, so `io::copy` can hash a reader.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{Fnv64, FnvHasher};
+/// let mut file: &[u8] = b"Hash this!";
+/// let mut hasher = Fnv64::new();
+/// std::io::copy(&mut file, &mut hasher).unwrap();
+/// assert_eq!(hasher.finalize(), Fnv64::hash(b"Hash this!"));
+/// ```
+impl<P: FnvParams> Write for Fnv<P> {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.update(bytes);
+        Ok(bytes.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_write {
+    ($($hasher:ty),+) => {$(
+        impl Write for $hasher {
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+                self.update(bytes);
+                Ok(bytes.len())
+            }
+
+            #[inline]
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+    )+};
+}
+
+impl_write!(Fnv16, Fnv24, Fnv48, Fnv56, DynFnvHasher);
+
+/// A reader that hashes every byte read through it.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{Fnv64, FnvHasher, HashingReader};
+/// # use std::io::Read;
+/// let mut reader = HashingReader::<_, Fnv64>::new(&b"Hash this!"[..]);
+/// let mut contents = Vec::new();
+/// reader.read_to_end(&mut contents).unwrap();
+/// assert_eq!(reader.finalize().as_bytes(), Fnv64::hash(b"Hash this!").as_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingReader<R, H> {
+    inner: R,
+    hasher: H,
+}
+
+impl<R, H: FnvHasher> HashingReader<R, H> {
+    /// Wraps `inner`, hashing with a new `H`.
+    pub fn new(inner: R) -> Self {
+        HashingReader::with_hasher(inner, H::new())
+    }
+
+    /// Wraps `inner`, continuing from `hasher`.
+    pub fn with_hasher(inner: R, hasher: H) -> Self {
+        HashingReader { inner, hasher }
+    }
+
+    /// Returns the hash of the bytes read so far.
+    pub fn finalize(&self) -> FnvHashResult {
+        self.hasher.finalize().into()
+    }
+
+    /// Returns the hasher.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped reader.
+    ///
+    /// Bytes read directly from it are not hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the reader and the hasher.
+    pub fn into_parts(self) -> (R, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<R: Read, H: FnvHasher> Read for HashingReader<R, H> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// A writer that hashes every byte written through it.
+///
+/// Only the bytes the wrapped writer accepts are hashed, so the hash always matches what was
+/// written.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{Fnv64, FnvHasher, HashingWriter};
+/// # use std::io::Write;
+/// let mut writer = HashingWriter::<_, Fnv64>::new(Vec::new());
+/// writer.write_all(b"Hash this!").unwrap();
+/// assert_eq!(writer.finalize().as_bytes(), Fnv64::hash(b"Hash this!").as_bytes());
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashingWriter<W, H> {
+    inner: W,
+    hasher: H,
+}
+
+impl<W, H: FnvHasher> HashingWriter<W, H> {
+    /// Wraps `inner`, hashing with a new `H`.
+    pub fn new(inner: W) -> Self {
+        HashingWriter::with_hasher(inner, H::new())
+    }
+
+    /// Wraps `inner`, continuing from `hasher`.
+    pub fn with_hasher(inner: W, hasher: H) -> Self {
+        HashingWriter { inner, hasher }
+    }
+
+    /// Returns the hash of the bytes written so far.
+    pub fn finalize(&self) -> FnvHashResult {
+        self.hasher.finalize().into()
+    }
+
+    /// Returns the hasher.
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped writer.
+    ///
+    /// Bytes written directly to it are not hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the writer and the hasher.
+    pub fn into_parts(self) -> (W, H) {
+        (self.inner, self.hasher)
+    }
+}
+
+impl<W: Write, H: FnvHasher> Write for HashingWriter<W, H> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(bytes)?;
+        self.hasher.update(&bytes[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -1,27 +1,122 @@
+#![no_std]
 #![cfg_attr(all(doc, CHANNEL_NIGHTLY), feature(doc_auto_cfg))]
-#![doc = include_str!("../README.md")]
+// the README's examples use the `std` collections and `alloc` encodings
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod consts;
+mod error;
 mod macros;
 mod hash_result;
+mod encoding;
+mod fnv_digest;
+mod range;
+mod generic;
+mod algorithm;
+mod tagged;
+#[cfg(feature = "bigint")]
+mod parameters;
+#[cfg(feature = "digest")]
+mod rustcrypto;
+#[cfg(feature = "serde")]
+mod serialization;
+mod wide_uint;
+#[cfg(feature = "tokio")]
+mod async_io;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+mod random_state;
 #[cfg(test)]
+mod tests;
 
 
-#[cfg(feature = "bigint")]
-use crypto_bigint::{Uint, U256, U512, U1024};
-
 use paste::paste;
-use std::collections::{HashMap, HashSet};
 use core::hash::{Hasher, BuildHasherDefault};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 pub use hash_result::FnvHashResult;
+pub use fnv_digest::FnvDigest;
+pub use generic::{Fnv, FnvParams, FnvVariant, FnvWord};
+pub use algorithm::{DynFnvHasher, FnvAlgorithm};
+pub use error::FnvError;
+pub use tagged::FnvTaggedHash;
+pub use wide_uint::WideUint;
+#[cfg(feature = "bigint")]
+pub use parameters::{fnv_parameters, FnvParameters};
+#[cfg(feature = "tokio")]
+pub use async_io::{FnvHashFuture, FnvStreamExt, HashingAsyncReader, HashingAsyncWriter, HashingStream};
+#[cfg(feature = "std")]
+pub use io::{HashingReader, HashingWriter};
+#[cfg(feature = "std")]
+pub use random_state::{FnvRandomState, FnvRandomHashMap, FnvRandomHashSet};
 
 
 pub trait FnvHasher {
+    /// The digest produced by this hasher, e.g. `FnvDigest<8>` for a 64-bit hasher.
+    type Output: Into<FnvHashResult>;
+
     fn new() -> Self;
     fn update(&mut self, bytes: &[u8]);
-    fn finalize(&self) -> FnvHashResult;
-    fn hash<T: AsRef<[u8]>>(data: T) -> FnvHashResult;
+    fn finalize(&self) -> Self::Output;
+    fn hash<T: AsRef<[u8]>>(data: T) -> Self::Output;
+
+    /// Finalize the hasher and map the hash onto `0..range` with the "lazy mod mapping".
+    ///
+    /// See [`FnvHashResult::lazy_mod`].
+    #[inline]
+    fn finalize_lazy_mod(&self, range: u64) -> u64 {
+        let hash: FnvHashResult = self.finalize().into();
+        hash.lazy_mod(range)
+    }
+
+    /// Finalize the hasher and map the hash onto `0..range` with the "retry method".
+    ///
+    /// See [`FnvHashResult::retry_mod`].
+    #[inline]
+    fn finalize_retry_mod(&self, range: u64) -> Result<u64, FnvError> {
+        let hash: FnvHashResult = self.finalize().into();
+        hash.retry_mod(range)
+    }
+
+    /// Resets the hasher to its initial state, as if it were just created with [`new`].
+    ///
+    /// [`new`]: FnvHasher::new
+    #[inline]
+    fn reset(&mut self)
+    where
+        Self: Sized,
+    {
+        *self = Self::new();
+    }
+
+    /// Finalize the hasher, then reset it so it can be reused.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{Fnv64, FnvHasher};
+    /// let mut hasher = Fnv64::new();
+    /// hasher.update(b"first");
+    /// assert_eq!(hasher.finalize_reset(), Fnv64::hash(b"first"));
+    ///
+    /// hasher.update(b"second");
+    /// assert_eq!(hasher.finalize_reset(), Fnv64::hash(b"second"));
+    /// ```
+    #[inline]
+    fn finalize_reset(&mut self) -> Self::Output
+    where
+        Self: Sized,
+    {
+        let output = self.finalize();
+        self.reset();
+        output
+    }
 }
 
 
@@ -29,13 +124,77 @@ macros::create_fnvhasher!(32);
 macros::create_fnvhasher!(64);
 macros::create_fnvhasher!(128);
 
-#[cfg(feature = "bigint")]
+macros::create_fnvhasher!(fnv1, 32);
+macros::create_fnvhasher!(fnv1, 64);
+macros::create_fnvhasher!(fnv1, 128);
+
+macros::create_fnvhasher!(fnv0, 32);
+macros::create_fnvhasher!(fnv0, 64);
+macros::create_fnvhasher!(fnv0, 128);
+
 macros::create_fnvhasher_bigint!(256);
-#[cfg(feature = "bigint")]
 macros::create_fnvhasher_bigint!(512);
-#[cfg(feature = "bigint")]
 macros::create_fnvhasher_bigint!(1024);
 
+macros::create_fnvhasher_bigint!(fnv1, 256);
+macros::create_fnvhasher_bigint!(fnv1, 512);
+macros::create_fnvhasher_bigint!(fnv1, 1024);
+
+macros::create_fnvhasher_bigint!(fnv0, 256);
+macros::create_fnvhasher_bigint!(fnv0, 512);
+macros::create_fnvhasher_bigint!(fnv0, 1024);
+
+macros::create_fnvhasher_folded!(16, 32);
+macros::create_fnvhasher_folded!(24, 32);
+macros::create_fnvhasher_folded!(48, 64);
+macros::create_fnvhasher_folded!(56, 64);
+
+
+/// One-time FNV-1a hash of `bytes`, xor-folded down to `bits` bits.
+///
+/// Following the FNV spec, the hash is computed with the smallest FNV width that is at least
+/// `bits` wide and then folded with [`FnvHashResult::xor_fold`]. Widths that are already a
+/// supported FNV size are returned unfolded.
+///
+/// # Panics:
+///
+/// Panics if `bits` is zero or wider than the largest FNV hasher (1024 bits).
+///
+/// # Example:
+///
+/// ```
+/// # let bytes = b"Hash this!";
+/// let hash = fnv_rs::hash_folded(bytes, 20);
+/// assert_eq!(hash.len(), 3);
+/// ```
+pub fn hash_folded<T: AsRef<[u8]>>(bytes: T, bits: u32) -> FnvHashResult {
+    let hash: FnvHashResult = match bits {
+        0..=32 => Fnv32::hash(bytes).into(),
+        33..=64 => Fnv64::hash(bytes).into(),
+        65..=128 => Fnv128::hash(bytes).into(),
+        129..=256 => Fnv256::hash(bytes).into(),
+        257..=512 => Fnv512::hash(bytes).into(),
+        513..=1024 => Fnv1024::hash(bytes).into(),
+        _ => panic!("no FNV hasher is wide enough to fold to {} bits", bits),
+    };
+
+    hash.xor_fold(bits)
+}
+
+
+/// `finish` repeats the 32-bit hash in both halves, as std's `HashMap` takes its control bits
+/// from the top of the hash.
+impl Hasher for Fnv32 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        (self.hash as u64) << 32 | self.hash as u64
+    }
+}
 
 impl Hasher for Fnv64 {
     #[inline]
@@ -45,15 +204,63 @@ impl Hasher for Fnv64 {
 
     #[inline]
     fn finish(&self) -> u64 {
-        self.0
+        self.hash
+    }
+}
+
+/// `finish` xor-folds the 128-bit hash down to 64 bits.
+impl Hasher for Fnv128 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        ((self.hash >> 64) ^ self.hash) as u64
     }
 }
 
+/// Xor-folds a wide, big-endian hash down to 64 bits by xoring all of its 64-bit words.
+#[inline]
+fn fold_to_u64(bytes: &[u8]) -> u64 {
+    bytes.chunks_exact(8).fold(0, |hash, word| hash ^ u64::from_be_bytes(word.try_into().unwrap()))
+}
+
+macro_rules! impl_hasher_bigint {
+    ($($x:literal),+) => { paste! { $(
+        #[doc = "`finish` xor-folds the " $x "-bit hash down to 64 bits."]
+        impl Hasher for [<Fnv $x>] {
+            #[inline]
+            fn write(&mut self, bytes: &[u8]) {
+                self.update(bytes);
+            }
+
+            #[inline]
+            fn finish(&self) -> u64 {
+                fold_to_u64(self.finalize().as_bytes())
+            }
+        }
+    )+ }};
+}
+
+impl_hasher_bigint!(256, 512, 1024);
+
 /// A builder for default FNV hashers.
 pub type FnvBuildHasher = BuildHasherDefault<Fnv64>;
 
 /// A `HashMap` using a default FNV hasher.
+#[cfg(feature = "std")]
 pub type FnvHashMap<K, V> = HashMap<K, V, FnvBuildHasher>;
 
 /// A `HashSet` using a default FNV hasher.
+#[cfg(feature = "std")]
 pub type FnvHashSet<T> = HashSet<T, FnvBuildHasher>;
+
+macros::create_fnv_collections!(32);
+macros::create_fnv_collections!(64);
+macros::create_fnv_collections!(128);
+
+macros::create_fnv_collections!(256);
+macros::create_fnv_collections!(512);
+macros::create_fnv_collections!(1024);
@@ -1,12 +1,50 @@
 macro_rules! create_fnvhasher {
     ($x:literal) => { paste! {
+        $crate::macros::create_fnvhasher!(@hasher [<Fnv $x>], $x, "FNV-1a", consts::[<FNV_OFFSET_ $x>], Fnv1a, fnv1a);
+    }};
+    (fnv1, $x:literal) => { paste! {
+        $crate::macros::create_fnvhasher!(@hasher [<Fnv1_ $x>], $x, "FNV-1", consts::[<FNV_OFFSET_ $x>], Fnv1, fnv1);
+    }};
+    (fnv0, $x:literal) => { paste! {
+        $crate::macros::create_fnvhasher!(@hasher [<Fnv0_ $x>], $x, "FNV-0", 0, Fnv0, fnv1);
+    }};
 
-        #[doc = "A FNV-1a hasher that produces a " $x "-bit output."]
+    (@step fnv1a, $hash:ident, $byte:expr, $prime:expr) => {
+        $hash ^= $byte;
+        $hash = $hash.wrapping_mul($prime);
+    };
+    (@step fnv1, $hash:ident, $byte:expr, $prime:expr) => {
+        $hash = $hash.wrapping_mul($prime);
+        $hash ^= $byte;
+    };
+
+    (@hasher $name:ident, $x:literal, $algo:literal, $offset:expr, $variant:ident, $step:ident) => { paste! {
+
+        #[doc = "The " $algo " parameters of [`" $name "`]."]
+        #[derive(Debug)]
+        pub struct [<$name Params>];
+
+        impl FnvParams for [<$name Params>] {
+            type Word = [<u $x>];
+
+            const PRIME: [<u $x>] = consts::[<FNV_PRIME_ $x>];
+            const OFFSET_BASIS: [<u $x>] = $offset;
+            const VARIANT: FnvVariant = FnvVariant::$variant;
+
+            #[inline]
+            fn unmultiply(hash: [<u $x>]) -> [<u $x>] {
+                hash.wrapping_mul(consts::[<FNV_PRIME_INVERSE_ $x>])
+            }
+        }
+
+        #[doc = "A " $algo " hasher that produces a " $x "-bit output."]
         ///
         /// # Examples:
         ///
         /// ```
-        #[doc = "let mut hasher = Fnv" $x "::new();"]
+        #[doc = "# use fnv_rs::{" $name ", FnvHasher};"]
+        /// # let bytes = b"Hash this!";
+        #[doc = "let mut hasher = " $name "::new();"]
         /// hasher.update(bytes);
         /// hasher.finalize();
         /// ```
@@ -14,114 +52,148 @@ macro_rules! create_fnvhasher {
         /// OR
         ///
         /// ```
-        #[doc = "let hash = Fnv" $x "::hash(bytes);"]
+        #[doc = "# use fnv_rs::{" $name ", FnvHasher};"]
+        /// # let bytes = b"Hash this!";
+        #[doc = "let hash = " $name "::hash(bytes);"]
         /// ```
-        #[derive(Debug)]
-        pub struct [<Fnv $x>]([<u $x>]);
-
-        impl Default for [<Fnv $x>] {
-            #[inline]
-            fn default() -> [<Fnv $x>] {
-                [<Fnv $x>](consts::[<FNV_OFFSET_ $x>])
-            }
-        }
+        pub type $name = Fnv<[<$name Params>]>;
 
-        impl FnvHasher for [<Fnv $x>] {
-
-            #[doc = "Creates a new default `Fnv" $x "` object."]
-            ///
-            /// # Example:
-            ///
-            /// ```
-            #[doc = "let hasher = Fnv" $x "::new();"]
-            /// ```
-            #[inline]
-            fn new() -> Self {
-                [<Fnv $x>]::default()
-            }
-
-            #[doc = "Incrementally update the `Fnv" $x "` object."]
-            ///
-            /// This method is best used when you need to update the hasher multiple times.
-            /// If you only need to hash a single piece of data, consider using the [`hash`] method instead.
+        impl $name {
+            #[doc = "Hashes `bytes` in a `const` context, returning the raw `u" $x "` hash."]
             ///
-            #[doc = "[`hash`]: Fnv" $x "::hash"]
+            /// This is useful for hashing static data at compile time, e.g. to use hashes as
+            /// `const` items or match arms.
             ///
             /// # Example:
             ///
             /// ```
-            /// hasher.update(bytes);
+            #[doc = "# use fnv_rs::" $name ";"]
+            #[doc = "const ID: u" $x " = " $name "::const_hash(b\"Hash this!\");"]
             /// ```
-            #[inline]
-            fn update(&mut self, bytes: &[u8]) {
-                let mut hash = self.0;
+            pub const fn const_hash(bytes: &[u8]) -> [<u $x>] {
+                let mut hash: [<u $x>] = $offset;
 
-                for byte in bytes.into_iter() {
-                    hash = hash ^ (*byte as [<u $x>]);
-                    hash = hash.wrapping_mul(consts::[<FNV_PRIME_ $x>]);
+                let mut i = 0;
+                while i < bytes.len() {
+                    $crate::macros::create_fnvhasher!(@step $step, hash, bytes[i] as [<u $x>], consts::[<FNV_PRIME_ $x>]);
+                    i += 1;
                 }
 
-                self.0 = hash;
+                hash
             }
+        }
+
+        impl From<$name> for [<u $x>] {
+            fn from(value: $name) -> Self {
+                value.hash
+            }
+        }
+    }};
+}
+
+macro_rules! create_fnvhasher_bigint {
+    ($x:literal) => { paste! {
+        $crate::macros::create_fnvhasher_bigint!(@hasher [<Fnv $x>], $x, "FNV-1a", consts::[<FNV_OFFSET_ $x>], Fnv1a, fnv1a);
+    }};
+    (fnv1, $x:literal) => { paste! {
+        $crate::macros::create_fnvhasher_bigint!(@hasher [<Fnv1_ $x>], $x, "FNV-1", consts::[<FNV_OFFSET_ $x>], Fnv1, fnv1);
+    }};
+    (fnv0, $x:literal) => { paste! {
+        $crate::macros::create_fnvhasher_bigint!(@hasher [<Fnv0_ $x>], $x, "FNV-0", WideUint::ZERO, Fnv0, fnv1);
+    }};
+
+    (@step fnv1a, $hash:ident, $byte:expr, $prime:expr) => {
+        $hash = $hash.xor_byte($byte);
+        $hash = $hash.wrapping_mul_sparse(&$prime);
+    };
+    (@step fnv1, $hash:ident, $byte:expr, $prime:expr) => {
+        $hash = $hash.wrapping_mul_sparse(&$prime);
+        $hash = $hash.xor_byte($byte);
+    };
+
+    (@hasher $name:ident, $x:literal, $algo:literal, $offset:expr, $variant:ident, $step:ident) => { paste! {
+
+        #[doc = "The " $algo " parameters of [`" $name "`]."]
+        #[derive(Debug)]
+        pub struct [<$name Params>];
+
+        impl FnvParams for [<$name Params>] {
+            type Word = WideUint<{ $x / 64 }>;
+
+            const PRIME: WideUint<{ $x / 64 }> = consts::[<FNV_PRIME_ $x>];
+            const OFFSET_BASIS: WideUint<{ $x / 64 }> = $offset;
+            const VARIANT: FnvVariant = FnvVariant::$variant;
 
-            #[doc = "Finalize the `Fnv" $x "` object."]
-            ///
-            /// # Example:
-            ///
-            /// ```
-            /// hasher.finalize();
-            /// ```
             #[inline]
-            fn finalize(&self) -> FnvHashResult {
-                FnvHashResult::[<from_u $x>](self.0)
+            fn multiply(hash: WideUint<{ $x / 64 }>) -> WideUint<{ $x / 64 }> {
+                hash.wrapping_mul_sparse(&consts::[<FNV_PRIME_ $x _SPARSE>])
             }
 
-            #[doc = "One-time use of the `Fnv" $x "` object."]
+            #[inline]
+            fn unmultiply(hash: WideUint<{ $x / 64 }>) -> WideUint<{ $x / 64 }> {
+                WideUint::wrapping_mul(&hash, &consts::[<FNV_PRIME_INVERSE_ $x>])
+            }
+        }
+
+        #[doc = "A " $algo " hasher that produces a " $x "-bit output."]
+        ///
+        /// # Examples:
+        ///
+        /// ```
+        #[doc = "# use fnv_rs::{" $name ", FnvHasher};"]
+        /// # let bytes = b"Hash this!";
+        #[doc = "let mut hasher = " $name "::new();"]
+        /// hasher.update(bytes);
+        /// hasher.finalize();
+        /// ```
+        ///
+        /// OR
+        ///
+        /// ```
+        #[doc = "# use fnv_rs::{" $name ", FnvHasher};"]
+        /// # let bytes = b"Hash this!";
+        #[doc = "let hash = " $name "::hash(bytes);"]
+        /// ```
+        pub type $name = Fnv<[<$name Params>]>;
+
+        impl $name {
+            #[doc = "Hashes `bytes` in a `const` context, returning the same digest as [`hash`](" $name "::hash)."]
             ///
-            /// Using this method is shorthand for the following:
+            /// This is useful for hashing static data at compile time, e.g. to use hashes as
+            /// `const` items.
             ///
-            /// ```
-            #[doc = "let mut hasher = Fnv" $x "::new();"]
-            /// hasher.update(bytes);
-            /// hasher.finalize();
-            /// ```
             /// # Example:
             ///
             /// ```
-            #[doc = "let hash = Fnv" $x "::hash(bytes);"]
+            #[doc = "# use fnv_rs::{" $name ", FnvDigest};"]
+            #[doc = "const ID: FnvDigest<{ " $x " / 8 }> = " $name "::const_hash(b\"Hash this!\");"]
             /// ```
-            #[inline]
-            fn hash<T: AsRef<[u8]>>(bytes: T) -> FnvHashResult {
-                let mut hash = consts::[<FNV_OFFSET_ $x>];
+            pub const fn const_hash(bytes: &[u8]) -> FnvDigest<{ $x / 8 }> {
+                let mut hash: WideUint<{ $x / 64 }> = $offset;
 
-                for byte in bytes.as_ref().into_iter() {
-                    hash = hash ^ (*byte as [<u $x>]);
-                    hash = hash.wrapping_mul(consts::[<FNV_PRIME_ $x>]);
+                let mut i = 0;
+                while i < bytes.len() {
+                    $crate::macros::create_fnvhasher_bigint!(@step $step, hash, bytes[i], consts::[<FNV_PRIME_ $x _SPARSE>]);
+                    i += 1;
                 }
 
-                FnvHashResult::[<from_u $x>](hash)
-            }
-        }
-
-        impl From<[<Fnv $x>]> for [<u $x>] {
-            fn from(value: [<Fnv $x>]) -> Self {
-                value.0
+                FnvDigest(hash.to_be_bytes())
             }
         }
-    }}
+    }};
 }
 
-#[cfg(feature = "bigint")]
-use crypto_bigint::{Uint, U256, U512, U1024};
 
-macro_rules! create_fnvhasher_bigint {
-    ($x:literal) => { paste! {
+macro_rules! create_fnvhasher_folded {
+    ($x:literal, $source:literal) => { paste! {
 
-        #[doc = "A FNV-1a hasher that produces a " $x "-bit output."]
+        #[doc = "A FNV-1a hasher that produces a " $x "-bit output by xor-folding [`Fnv" $source "`]."]
         ///
         /// # Examples:
         ///
         /// ```
+        #[doc = "# use fnv_rs::{Fnv" $x ", FnvHasher};"]
+        /// # let bytes = b"Hash this!";
         #[doc = "let mut hasher = Fnv" $x "::new();"]
         /// hasher.update(bytes);
         /// hasher.finalize();
@@ -130,111 +202,169 @@ macro_rules! create_fnvhasher_bigint {
         /// OR
         ///
         /// ```
+        #[doc = "# use fnv_rs::{Fnv" $x ", FnvHasher};"]
+        /// # let bytes = b"Hash this!";
         #[doc = "let hash = Fnv" $x "::hash(bytes);"]
         /// ```
-        #[derive(Debug)]
-        #[cfg(feature = "bigint")]
-        pub struct [<Fnv $x>]([<U $x>]);
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct [<Fnv $x>]([<Fnv $source>]);
+
+        impl [<Fnv $x>] {
+            #[doc = "Hashes `bytes` in a `const` context, returning the folded hash in the low " $x " bits of a `u" $source "`."]
+            ///
+            /// # Example:
+            ///
+            /// ```
+            #[doc = "# use fnv_rs::Fnv" $x ";"]
+            #[doc = "const ID: u" $source " = Fnv" $x "::const_hash(b\"Hash this!\");"]
+            /// ```
+            pub const fn const_hash(bytes: &[u8]) -> [<u $source>] {
+                let hash = [<Fnv $source>]::const_hash(bytes);
+                ((hash >> $x) ^ hash) & ((1 << $x) - 1)
+            }
+
+            #[doc = "Exports the unfolded, " $source "-bit running hash, to resume hashing later with [`from_state`](Fnv" $x "::from_state)."]
+            ///
+            #[doc = "See [`Fnv::state`](crate::Fnv::state) for the encoding."]
+            #[inline]
+            pub fn state(&self) -> FnvDigest<{ $source / 8 }> {
+                self.0.state()
+            }
 
-        #[cfg(feature = "bigint")]
-        impl Default for [<Fnv $x>] {
+            #[doc = "Resumes hashing from a state exported by [`state`](Fnv" $x "::state)."]
             #[inline]
-            fn default() -> [<Fnv $x>] {
-                [<Fnv $x>](consts::[<FNV_OFFSET_ $x>])
+            pub fn from_state(state: FnvDigest<{ $source / 8 }>) -> Self {
+                [<Fnv $x>]([<Fnv $source>]::from_state(state))
+            }
+
+            /// Removes `bytes` from the end of the hashed data, undoing [`update`](FnvHasher::update).
+            ///
+            #[doc = "See [`Fnv::unupdate`](crate::Fnv::unupdate)."]
+            #[inline]
+            pub fn unupdate(&mut self, bytes: &[u8]) {
+                self.0.unupdate(bytes);
+            }
+
+            #[inline]
+            fn fold(hash: [<u $source>]) -> FnvDigest<{ $x / 8 }> {
+                let folded = ((hash >> $x) ^ hash) & ((1 << $x) - 1);
+                FnvDigest(folded.to_be_bytes()[($source - $x) / 8..].try_into().unwrap())
             }
         }
 
-        #[cfg(feature = "bigint")]
         impl FnvHasher for [<Fnv $x>] {
+            type Output = FnvDigest<{ $x / 8 }>;
 
             #[doc = "Creates a new default `Fnv" $x "` object."]
             ///
             /// # Example:
             ///
             /// ```
+            #[doc = "# use fnv_rs::{Fnv" $x ", FnvHasher};"]
             #[doc = "let hasher = Fnv" $x "::new();"]
             /// ```
             #[inline]
-            fn new() -> [<Fnv $x>] {
+            fn new() -> Self {
                 [<Fnv $x>]::default()
             }
 
             #[doc = "Incrementally update the `Fnv" $x "` object."]
             ///
-            /// This method is best used when you need to update the hasher multiple times.
-            /// If you only need to hash a single piece of data, consider using the [`hash`] method instead.
-            ///
-            #[doc = "[`hash`]: Fnv" $x "::hash"]
-            ///
             /// # Example:
             ///
             /// ```
+            #[doc = "# use fnv_rs::{Fnv" $x ", FnvHasher};"]
+            #[doc = "# let mut hasher = Fnv" $x "::new();"]
+            /// # let bytes = b"Hash this!";
             /// hasher.update(bytes);
             /// ```
             #[inline]
             fn update(&mut self, bytes: &[u8]) {
-                let mut hash = self.0;
-
-                for byte in bytes.into_iter() {
-                    hash = hash ^ (Uint::from_u8(*byte));
-                    hash = hash.wrapping_mul(&consts::[<FNV_PRIME_ $x>]);
-                }
-
-                self.0 = hash;
+                self.0.update(bytes);
             }
 
-            #[doc = "Finalize the `Fnv" $x "` object."]
+            #[doc = "Finalize the `Fnv" $x "` object, folding the " $source "-bit hash down to " $x " bits."]
             ///
             /// # Example:
             ///
             /// ```
+            #[doc = "# use fnv_rs::{Fnv" $x ", FnvHasher};"]
+            #[doc = "# let hasher = Fnv" $x "::new();"]
             /// hasher.finalize();
             /// ```
             #[inline]
-            fn finalize(&self) -> FnvHashResult {
-                FnvHashResult::from_bigint(self.0)
+            fn finalize(&self) -> FnvDigest<{ $x / 8 }> {
+                [<Fnv $x>]::fold(self.0.finalize().into())
             }
 
             #[doc = "One-time use of the `Fnv" $x "` object."]
             ///
-            /// Using this method is shorthand for the following:
-            ///
-            /// ```
-            #[doc = "let mut hasher = Fnv" $x "::new();"]
-            /// hasher.update(bytes);
-            /// hasher.finalize();
-            /// ```
             /// # Example:
             ///
             /// ```
+            #[doc = "# use fnv_rs::{Fnv" $x ", FnvHasher};"]
+            /// # let bytes = b"Hash this!";
             #[doc = "let hash = Fnv" $x "::hash(bytes);"]
             /// ```
             #[inline]
-            fn hash<T: AsRef<[u8]>>(bytes: T) -> FnvHashResult {
-                let mut hash = consts::[<FNV_OFFSET_ $x>];
-
-                for byte in bytes.as_ref().into_iter() {
-                    hash = hash ^ (Uint::from_u8(*byte));
-                    hash = hash.wrapping_mul(&consts::[<FNV_PRIME_ $x>]);
-                }
-
-                FnvHashResult::from_bigint(hash)
+            fn hash<T: AsRef<[u8]>>(bytes: T) -> FnvDigest<{ $x / 8 }> {
+                [<Fnv $x>]::fold([<Fnv $source>]::hash(bytes).into())
             }
         }
-    }}
+    }};
+}
+
+macro_rules! create_fnv_collections {
+    ($x:literal) => { paste! {
+
+        #[doc = "A builder for [`Fnv" $x "`] hashers."]
+        pub type [<Fnv $x BuildHasher>] = BuildHasherDefault<[<Fnv $x>]>;
+
+        #[doc = "A `HashMap` using an [`Fnv" $x "`] hasher."]
+        #[cfg(feature = "std")]
+        pub type [<Fnv $x HashMap>]<K, V> = HashMap<K, V, [<Fnv $x BuildHasher>]>;
+
+        #[doc = "A `HashSet` using an [`Fnv" $x "`] hasher."]
+        #[cfg(feature = "std")]
+        pub type [<Fnv $x HashSet>]<T> = HashSet<T, [<Fnv $x BuildHasher>]>;
+    }};
 }
 
 
 pub(crate) use create_fnvhasher;
+pub(crate) use create_fnv_collections;
+pub(crate) use create_fnvhasher_folded;
 pub(crate) use create_fnvhasher_bigint;
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use crate::{consts, Fnv, FnvDigest, FnvHasher, FnvParams, FnvVariant, WideUint};
+    use paste::paste;
+    use std::string::ToString;
 
     #[test]
     fn test_macros() {
         create_fnvhasher!(32);
+        create_fnvhasher!(fnv1, 32);
+        create_fnvhasher!(fnv0, 32);
+
+        assert_eq!(Fnv32::hash(b"foobar").to_string(), "BF9CF968");
+        assert_eq!(Fnv1_32::hash(b"foobar").to_string(), "31F0B262");
+        assert_eq!(Fnv0_32::hash(b"foobar").to_string(), "B74BB5EF");
+        assert_eq!(Fnv32::const_hash(b"foobar"), 0xBF9CF968);
+    }
+
+    #[test]
+    fn test_macros_bigint() {
         create_fnvhasher_bigint!(256);
+        create_fnvhasher_bigint!(fnv1, 256);
+        create_fnvhasher_bigint!(fnv0, 256);
+
+        assert_eq!(Fnv256::hash(b"a").to_string(), "63323FB0F35303EC28DC751D0A33BDFA4DE6A99B7266494F6183B2716811637C");
+        assert_eq!(Fnv1_256::hash(b"a").to_string(), "63323FB0F35303EC28DC561D0A33BDFA4DE6A99B7266494F6183B2716811381E");
+        assert_eq!(Fnv0_256::hash(b"a").to_string(), "0000000000000000000000000000000000000000000000000000000000000061");
+        assert_eq!(Fnv256::const_hash(b"a"), Fnv256::hash(b"a"));
+        assert_eq!(Fnv1_256::const_hash(b"a"), Fnv1_256::hash(b"a"));
+        assert_eq!(Fnv0_256::const_hash(b"a"), Fnv0_256::hash(b"a"));
     }
 }
@@ -0,0 +1,104 @@
+//! Deriving FNV primes and offset bases from the FNV spec's selection criteria.
+//!
+//! For a `2^s`-bit hash, the FNV prime is the smallest prime `p = 256^t + 2^8 + b` with
+//! `t = floor((5 + 2^s) / 12)`, where `0 < b < 2^8` has 4 or 5 one bits and
+//! `p mod (2^40 - 2^24 - 1) > 2^24 + 2^8 + 2^7`. The offset basis is the FNV-0 hash of the spec's
+//! signature string. The spec only covers `5 <= s <= 10`: larger FNV primes are too sparse for
+//! these criteria, and indeed no such prime exists for 2048 or 4096 bits.
+
+use crypto_bigint::modular::{MontyForm, MontyParams};
+use crypto_bigint::{NonZero, Odd, Uint, U1024, U2048, U4096};
+
+/// The string whose FNV-0 hash is the FNV-1 and FNV-1a offset basis.
+const SIGNATURE: &[u8] = b"chongo <Landon Curt Noll> /\\../\\";
+
+/// Bases for the Miller-Rabin test, deterministic below 2^64 and a vanishing error beyond.
+const WITNESSES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// The prime and offset basis of one FNV hash size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FnvParameters {
+    /// The hash size in bits.
+    pub bits: u32,
+    /// The FNV prime, e.g. `0x01000193` for 32 bits.
+    pub prime: U1024,
+    /// The FNV-1 and FNV-1a offset basis, e.g. `0x811C9DC5` for 32 bits.
+    pub offset_basis: U1024,
+}
+
+/// Derives the FNV prime and offset basis for a `bits`-bit hash.
+///
+/// Returns `None` unless `bits` is a power of two from 32 through 1024, the sizes the FNV spec
+/// defines parameters for. At 2048 and 4096 bits the search runs but no prime meets the spec's
+/// criteria; larger sizes are not searched.
+///
+/// # Example:
+///
+/// ```
+/// # use crypto_bigint::U1024;
+/// let parameters = fnv_rs::fnv_parameters(32).unwrap();
+/// assert_eq!(parameters.prime, U1024::from_u32(0x01000193));
+/// assert_eq!(parameters.offset_basis, U1024::from_u32(0x811C9DC5));
+/// ```
+pub fn fnv_parameters(bits: u32) -> Option<FnvParameters> {
+    if !bits.is_power_of_two() || bits < 32 {
+        return None;
+    }
+
+    let prime: U1024 = match bits {
+        32..=1024 => fnv_prime(bits)?,
+        // `FnvParameters` has no room for a wider prime, but the criteria admit none
+        2048 => fnv_prime::<{ U2048::LIMBS }>(bits).map(|_| unreachable!("no 2048-bit FNV prime exists"))?,
+        4096 => fnv_prime::<{ U4096::LIMBS }>(bits).map(|_| unreachable!("no 4096-bit FNV prime exists"))?,
+        _ => return None,
+    };
+    let mask = U1024::MAX.shr_vartime(1024 - bits);
+    let offset_basis = SIGNATURE.iter().fold(U1024::ZERO, |hash, byte| {
+        hash.wrapping_mul(&prime).bitand(&mask).bitxor(&U1024::from_u8(*byte))
+    });
+
+    Some(FnvParameters { bits, prime, offset_basis })
+}
+
+fn fnv_prime<const LIMBS: usize>(bits: u32) -> Option<Uint<LIMBS>> {
+    let t = (5 + bits) / 12;
+    let base = Uint::<LIMBS>::ONE.shl_vartime(8 * t).wrapping_add(&Uint::from_u16(0x100));
+    let spread = NonZero::new(Uint::<LIMBS>::from_u64((1 << 40) - (1 << 24) - 1)).unwrap();
+    let spread_min = Uint::<LIMBS>::from_u64((1 << 24) + (1 << 8) + (1 << 7));
+
+    (1..=u8::MAX)
+        .filter(|b| matches!(b.count_ones(), 4 | 5))
+        .map(|b| base.wrapping_add(&Uint::from_u8(b)))
+        .filter(|p| p.rem(&spread) > spread_min)
+        .find(is_probable_prime)
+}
+
+fn is_probable_prime<const LIMBS: usize>(n: &Uint<LIMBS>) -> bool {
+    for witness in WITNESSES {
+        let witness = Uint::<LIMBS>::from_u8(witness);
+        if n.rem(&NonZero::new(witness).unwrap()) == Uint::ZERO {
+            return *n == witness;
+        }
+    }
+
+    // n - 1 = d * 2^r with d odd
+    let n_minus_one = n.wrapping_sub(&Uint::ONE);
+    let r = n_minus_one.trailing_zeros();
+    let d = n_minus_one.shr_vartime(r);
+
+    let params = MontyParams::new_vartime(Odd::new(*n).unwrap());
+    let one = MontyForm::one(params);
+    let minus_one = MontyForm::new(&n_minus_one, params);
+
+    WITNESSES.iter().all(|witness| {
+        let mut x = MontyForm::new(&Uint::from_u8(*witness), params).pow(&d);
+        if x == one || x == minus_one {
+            return true;
+        }
+
+        (1..r).any(|_| {
+            x = x.square();
+            x == minus_one
+        })
+    })
+}
@@ -0,0 +1,92 @@
+use core::fmt;
+use core::hash::{BuildHasher, Hasher};
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+
+use crate::{consts, Fnv64};
+
+/// A `BuildHasher` that gives each instance its own random FNV-1a offset basis.
+///
+/// Plain FNV always starts from the same offset basis, so an attacker who controls the keys of
+/// a map can precompute collisions. `FnvRandomState` draws a random offset basis for every
+/// instance (much like the standard library's `RandomState`), which keeps FNV's speed while
+/// making precomputed collisions useless across maps and processes.
+///
+/// This raises the bar, but FNV is not a keyed hash function: it is no substitute for SipHash
+/// when a map is fed entirely by untrusted input.
+///
+/// # Example:
+///
+/// ```
+/// use fnv_rs::FnvRandomHashMap;
+///
+/// let mut map = FnvRandomHashMap::default();
+/// map.insert(1, "one");
+/// ```
+#[derive(Clone)]
+pub struct FnvRandomState {
+    offset_basis: u64,
+}
+
+impl FnvRandomState {
+    /// Creates a new `FnvRandomState` with a random offset basis.
+    pub fn new() -> FnvRandomState {
+        FnvRandomState::with_seed(RandomState::new().build_hasher().finish())
+    }
+
+    /// Creates a new `FnvRandomState` whose offset basis is derived from `seed`.
+    ///
+    /// The same seed always produces the same hashes, which is useful for tests and for
+    /// reproducing bugs.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use core::hash::{BuildHasher, Hasher};
+    /// use fnv_rs::FnvRandomState;
+    ///
+    /// let mut a = FnvRandomState::with_seed(42).build_hasher();
+    /// let mut b = FnvRandomState::with_seed(42).build_hasher();
+    /// a.write(b"foobar");
+    /// b.write(b"foobar");
+    /// assert_eq!(a.finish(), b.finish());
+    /// ```
+    pub fn with_seed(seed: u64) -> FnvRandomState {
+        let offset_basis = match Fnv64::const_hash(&seed.to_le_bytes()) {
+            // a zero offset basis turns FNV-1a into FNV-0, where runs of zero bytes all hash to zero
+            0 => consts::FNV_OFFSET_64,
+            offset_basis => offset_basis,
+        };
+
+        FnvRandomState { offset_basis }
+    }
+}
+
+/// Leaves out the offset basis, which would let anyone who sees the output precompute collisions.
+impl fmt::Debug for FnvRandomState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FnvRandomState").finish_non_exhaustive()
+    }
+}
+
+impl Default for FnvRandomState {
+    #[inline]
+    fn default() -> FnvRandomState {
+        FnvRandomState::new()
+    }
+}
+
+impl BuildHasher for FnvRandomState {
+    type Hasher = Fnv64;
+
+    #[inline]
+    fn build_hasher(&self) -> Fnv64 {
+        Fnv64::from_word(self.offset_basis)
+    }
+}
+
+/// A `HashMap` using FNV with a random offset basis.
+pub type FnvRandomHashMap<K, V> = HashMap<K, V, FnvRandomState>;
+
+/// A `HashSet` using FNV with a random offset basis.
+pub type FnvRandomHashSet<T> = HashSet<T, FnvRandomState>;
@@ -0,0 +1,67 @@
+//! Mapping FNV hashes onto ranges that are not a power of two.
+//!
+//! The FNV spec describes two ways of doing this: the "lazy mod mapping", which simply takes
+//! the hash modulo the range, and the "retry method", which re-mixes the hash until it falls
+//! below the largest multiple of the range so the final modulo is unbiased.
+
+use crate::consts;
+use crate::wide_uint::WideUint;
+use paste::paste;
+
+
+/// Big-endian `bytes` modulo `range`.
+pub(crate) fn lazy_mod_bytes(bytes: &[u8], range: u64) -> u64 {
+    assert!(range > 0, "cannot map a hash onto an empty range");
+
+    let range = range as u128;
+    let rem = bytes.iter().fold(0u128, |rem, byte| ((rem << 8) | *byte as u128) % range);
+
+    rem as u64
+}
+
+macro_rules! create_retry {
+    ($x:literal) => { paste! {
+        pub(crate) fn [<retry_u $x>](mut hash: [<u $x>], range: u64) -> u64 {
+            assert!(range > 0, "cannot map a hash onto an empty range");
+
+            // every hash already fits in a range this large
+            if range as u128 > [<u $x>]::MAX as u128 {
+                return hash as u64;
+            }
+
+            let range = range as [<u $x>];
+            let retry_level = ([<u $x>]::MAX / range) * range;
+
+            while hash >= retry_level {
+                hash = hash
+                    .wrapping_mul(consts::[<FNV_PRIME_ $x>])
+                    .wrapping_add(consts::[<FNV_OFFSET_ $x>]);
+            }
+
+            (hash % range) as u64
+        }
+    }};
+}
+
+create_retry!(32);
+create_retry!(64);
+create_retry!(128);
+
+pub(crate) fn retry_wide<const LIMBS: usize>(
+    mut hash: WideUint<LIMBS>,
+    prime: &WideUint<LIMBS>,
+    offset: &WideUint<LIMBS>,
+    range: u64,
+) -> u64 {
+    assert!(range > 0, "cannot map a hash onto an empty range");
+
+    // `MAX - MAX % range`, where the remainder only touches the lowest limb
+    let mut retry_level = WideUint::<LIMBS>::MAX;
+    retry_level.0[0] -= WideUint::<LIMBS>::MAX.rem_u64(range);
+
+    while hash >= retry_level {
+        hash = hash.wrapping_mul(prime).wrapping_add(offset);
+    }
+
+    hash.rem_u64(range)
+}
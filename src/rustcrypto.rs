@@ -0,0 +1,71 @@
+//! The RustCrypto [`digest`] traits for every FNV hasher.
+//!
+//! With the `digest` feature, each hasher implements [`Digest`](digest::Digest) and can replace
+//! `sha2` or `blake3` in code generic over it. Since both [`FnvHasher`] and `digest` provide
+//! `new`, `update` and `finalize`, call them through the trait when both are in scope.
+//!
+//! # Example:
+//!
+//! ```
+//! use digest::Digest;
+//! use fnv_rs::Fnv64;
+//!
+//! fn checksum<D: Digest>(bytes: &[u8]) -> Vec<u8> {
+//!     D::digest(bytes).to_vec()
+//! }
+//!
+//! assert_eq!(checksum::<Fnv64>(b"Hash this!").len(), 8);
+//! ```
+
+use digest::consts::{U2, U3, U4, U6, U7, U8, U16, U32, U64, U128};
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use crate::FnvHasher;
+use crate::{Fnv16, Fnv24, Fnv32, Fnv48, Fnv56, Fnv64, Fnv128, Fnv256, Fnv512, Fnv1024};
+use crate::{Fnv1_32, Fnv1_64, Fnv1_128, Fnv1_256, Fnv1_512, Fnv1_1024};
+use crate::{Fnv0_32, Fnv0_64, Fnv0_128, Fnv0_256, Fnv0_512, Fnv0_1024};
+
+macro_rules! impl_digest {
+    ($($hasher:ty: $size:ty),+ $(,)?) => {$(
+        impl HashMarker for $hasher {}
+
+        impl OutputSizeUser for $hasher {
+            type OutputSize = $size;
+        }
+
+        impl Update for $hasher {
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                FnvHasher::update(self, data);
+            }
+        }
+
+        impl FixedOutput for $hasher {
+            #[inline]
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(FnvHasher::finalize(&self).as_bytes());
+            }
+        }
+
+        impl Reset for $hasher {
+            #[inline]
+            fn reset(&mut self) {
+                FnvHasher::reset(self);
+            }
+        }
+
+        impl FixedOutputReset for $hasher {
+            #[inline]
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(FnvHasher::finalize_reset(self).as_bytes());
+            }
+        }
+    )+};
+}
+
+impl_digest! {
+    Fnv16: U2, Fnv24: U3, Fnv32: U4, Fnv48: U6, Fnv56: U7,
+    Fnv64: U8, Fnv128: U16, Fnv256: U32, Fnv512: U64, Fnv1024: U128,
+    Fnv1_32: U4, Fnv1_64: U8, Fnv1_128: U16, Fnv1_256: U32, Fnv1_512: U64, Fnv1_1024: U128,
+    Fnv0_32: U4, Fnv0_64: U8, Fnv0_128: U16, Fnv0_256: U32, Fnv0_512: U64, Fnv0_1024: U128,
+}
@@ -0,0 +1,196 @@
+//! `serde` support for hashes and hasher state.
+//!
+//! Hashes serialize as uppercase hex in human-readable formats such as JSON, and as raw bytes in
+//! binary formats such as bincode. A hasher serializes as its [`state`](crate::Fnv::state), so an
+//! in-progress hash can be saved and resumed. A tagged hash serializes as its `algorithm:HEX`
+//! string, or as its multihash in binary formats.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::hash_result::MAX_LEN;
+use crate::{DynFnvHasher, Fnv, FnvAlgorithm, FnvDigest, FnvHashResult, FnvParams, FnvTaggedHash, FnvWord};
+use crate::{Fnv16, Fnv24, Fnv48, Fnv56};
+
+impl Serialize for FnvHashResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+struct HashVisitor;
+
+impl<'de> Visitor<'de> for HashVisitor {
+    type Value = FnvHashResult;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an FNV hash as a hex string or 1 to 128 bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<FnvHashResult, E> {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<FnvHashResult, E> {
+        if bytes.is_empty() || bytes.len() > MAX_LEN {
+            return Err(E::invalid_length(bytes.len(), &self));
+        }
+        Ok(FnvHashResult::from_slice(bytes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<FnvHashResult, A::Error> {
+        let mut bytes = [0u8; MAX_LEN];
+        let mut len = 0;
+        while let Some(byte) = seq.next_element()? {
+            if len == MAX_LEN {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+            bytes[len] = byte;
+            len += 1;
+        }
+        self.visit_bytes(&bytes[..len])
+    }
+}
+
+impl<'de> Deserialize<'de> for FnvHashResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HashVisitor)
+        } else {
+            deserializer.deserialize_bytes(HashVisitor)
+        }
+    }
+}
+
+impl<const N: usize> Serialize for FnvDigest<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_hash_result().serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for FnvDigest<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FnvDigest::try_from(FnvHashResult::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl<P: FnvParams> Serialize for Fnv<P>
+where
+    <P::Word as FnvWord>::Digest: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.state().serialize(serializer)
+    }
+}
+
+impl<'de, P: FnvParams> Deserialize<'de> for Fnv<P>
+where
+    <P::Word as FnvWord>::Digest: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Deserialize::deserialize(deserializer).map(Fnv::from_state)
+    }
+}
+
+macro_rules! impl_serde_folded {
+    ($($hasher:ident),+) => {$(
+        impl Serialize for $hasher {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.state().serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $hasher {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Deserialize::deserialize(deserializer).map($hasher::from_state)
+            }
+        }
+    )+};
+}
+
+impl_serde_folded!(Fnv16, Fnv24, Fnv48, Fnv56);
+
+impl Serialize for FnvAlgorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+struct AlgorithmVisitor;
+
+impl<'de> Visitor<'de> for AlgorithmVisitor {
+    type Value = FnvAlgorithm;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an FNV algorithm name such as \"fnv1a-64\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<FnvAlgorithm, E> {
+        s.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))
+    }
+}
+
+impl<'de> Deserialize<'de> for FnvAlgorithm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(AlgorithmVisitor)
+    }
+}
+
+/// Serializes as the pair `(algorithm, state)`.
+impl Serialize for DynFnvHasher {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.algorithm(), self.state()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DynFnvHasher {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (algorithm, state) = <(FnvAlgorithm, FnvHashResult)>::deserialize(deserializer)?;
+        DynFnvHasher::from_state(algorithm, state.as_bytes()).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for FnvTaggedHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let (bytes, len) = self.multihash_bytes();
+            serializer.serialize_bytes(&bytes[..len])
+        }
+    }
+}
+
+struct TaggedHashVisitor;
+
+impl<'de> Visitor<'de> for TaggedHashVisitor {
+    type Value = FnvTaggedHash;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a tagged FNV hash such as \"fnv1a-64:0B9712E03AC3958E\" or a multihash")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<FnvTaggedHash, E> {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<FnvTaggedHash, E> {
+        FnvTaggedHash::from_multihash(bytes).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for FnvTaggedHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(TaggedHashVisitor)
+        } else {
+            deserializer.deserialize_bytes(TaggedHashVisitor)
+        }
+    }
+}
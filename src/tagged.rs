@@ -0,0 +1,199 @@
+//! Self-describing hashes, tagged with the algorithm that produced them.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::encoding;
+use crate::{FnvAlgorithm, FnvError, FnvHashResult};
+
+/// The longest multihash: a 4-byte code, a 2-byte length and a 128-byte digest.
+#[cfg(any(feature = "alloc", feature = "serde"))]
+const MAX_MULTIHASH_LEN: usize = 134;
+
+/// A hash together with the [`FnvAlgorithm`] that produced it.
+///
+/// A tagged hash displays as `algorithm:HEX`, e.g. `fnv1a-64:0B9712E03AC3958E`, and parses back
+/// from that form. It also converts to and from Subresource Integrity strings and multihashes,
+/// see [`FnvAlgorithm::multihash_code`] for the codes.
+///
+/// FNV is not a cryptographic hash: [`verify`](FnvTaggedHash::verify) catches accidental
+/// corruption, not tampering.
+///
+/// # Example:
+///
+/// ```
+/// # use fnv_rs::{FnvAlgorithm, FnvTaggedHash};
+/// let tagged = FnvTaggedHash::compute(FnvAlgorithm::Fnv1a64, b"Hash this!");
+/// assert_eq!(tagged.to_string(), "fnv1a-64:0B9712E03AC3958E");
+///
+/// let stored: FnvTaggedHash = "fnv1a-64:0B9712E03AC3958E".parse().unwrap();
+/// assert_eq!(stored.algorithm(), FnvAlgorithm::Fnv1a64);
+/// assert!(stored.verify(b"Hash this!"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FnvTaggedHash {
+    algorithm: FnvAlgorithm,
+    hash: FnvHashResult,
+}
+
+impl FnvTaggedHash {
+    /// Tags `hash` with `algorithm`.
+    ///
+    /// Fails with [`FnvError::InvalidLength`] if `hash` is not as wide as `algorithm`'s hashes.
+    pub fn new(algorithm: FnvAlgorithm, hash: FnvHashResult) -> Result<Self, FnvError> {
+        let expected = algorithm.bits() as usize / 8;
+        if hash.len() != expected {
+            return Err(FnvError::InvalidLength { expected, actual: hash.len() });
+        }
+
+        Ok(FnvTaggedHash { algorithm, hash })
+    }
+
+    /// Hashes `data` with `algorithm`.
+    pub fn compute<T: AsRef<[u8]>>(algorithm: FnvAlgorithm, data: T) -> Self {
+        FnvTaggedHash { algorithm, hash: algorithm.hash(data) }
+    }
+
+    /// Returns the algorithm that produced the hash.
+    pub fn algorithm(&self) -> FnvAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns the hash.
+    pub fn hash(&self) -> FnvHashResult {
+        self.hash
+    }
+
+    /// Returns `true` if `data` hashes to this hash with this algorithm.
+    pub fn verify<T: AsRef<[u8]>>(&self, data: T) -> bool {
+        self.algorithm.hash(data) == self.hash
+    }
+
+    /// Returns a Subresource Integrity string, e.g. `fnv1a-64-C5cS4DrDlY4=`.
+    ///
+    /// The digest is in padded, standard Base64, as SRI requires.
+    #[cfg(feature = "alloc")]
+    pub fn to_sri(&self) -> alloc::string::String {
+        let mut sri = alloc::string::String::from(self.algorithm.name());
+        sri.push('-');
+        sri.push_str(&encoding::encode_bits(self.hash.as_bytes(), encoding::BASE64, 6, Some(4)));
+        sri
+    }
+
+    /// Parses a Subresource Integrity string, ignoring any `?` options after the digest.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{FnvAlgorithm, FnvTaggedHash};
+    /// let tagged = FnvTaggedHash::from_sri("fnv1a-64-C5cS4DrDlY4=").unwrap();
+    /// assert_eq!(tagged, FnvTaggedHash::compute(FnvAlgorithm::Fnv1a64, b"Hash this!"));
+    /// ```
+    pub fn from_sri(s: &str) -> Result<Self, FnvError> {
+        let s = s.split_once('?').map_or(s, |(s, _options)| s);
+        // Base64 has no `-`, so the digest starts after the last one
+        let (name, digest) = s.rsplit_once('-').ok_or(FnvError::UnknownAlgorithm)?;
+
+        let algorithm = name.parse()?;
+        let hash = encoding::decode_bits(digest, 6, 4, encoding::decode_base64)?;
+        FnvTaggedHash::new(algorithm, hash)
+    }
+
+    /// Returns this hash as a multihash: the varint code, the varint digest length and the digest.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{FnvAlgorithm, FnvTaggedHash};
+    /// let tagged = FnvTaggedHash::compute(FnvAlgorithm::Fnv1_128, b"Hash this!");
+    /// let multihash = tagged.to_multihash();
+    /// assert_eq!(&multihash[..4], &[0x80, 0xA1, 0xC0, 0x01]);
+    /// assert_eq!(FnvTaggedHash::from_multihash(&multihash).unwrap(), tagged);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_multihash(&self) -> alloc::vec::Vec<u8> {
+        let (bytes, len) = self.multihash_bytes();
+        bytes[..len].to_vec()
+    }
+
+    #[cfg(any(feature = "alloc", feature = "serde"))]
+    pub(crate) fn multihash_bytes(&self) -> ([u8; MAX_MULTIHASH_LEN], usize) {
+        let mut bytes = [0u8; MAX_MULTIHASH_LEN];
+        let mut len = write_varint(self.algorithm.multihash_code(), &mut bytes);
+        len += write_varint(self.hash.len() as u64, &mut bytes[len..]);
+        bytes[len..len + self.hash.len()].copy_from_slice(self.hash.as_bytes());
+
+        (bytes, len + self.hash.len())
+    }
+
+    /// Parses a multihash, as produced by `to_multihash`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::{FnvAlgorithm, FnvTaggedHash};
+    /// // the fnv1a-32 code, the digest length and the digest
+    /// let multihash = [0xA0, 0xC0, 0xC0, 0x01, 0x04, 0x0A, 0xC5, 0x80, 0x4E];
+    /// let tagged = FnvTaggedHash::from_multihash(&multihash).unwrap();
+    /// assert_eq!(tagged, FnvTaggedHash::compute(FnvAlgorithm::Fnv1a32, b"Hash this!"));
+    /// ```
+    pub fn from_multihash(bytes: &[u8]) -> Result<Self, FnvError> {
+        let (code, code_len) = read_varint(bytes)?;
+        let (len, len_len) = read_varint(&bytes[code_len..])?;
+        let digest = &bytes[code_len + len_len..];
+
+        let algorithm = FnvAlgorithm::from_multihash_code(code).ok_or(FnvError::UnknownAlgorithm)?;
+        let expected = algorithm.bits() as usize / 8;
+        if len != expected as u64 {
+            let actual = usize::try_from(len).unwrap_or(usize::MAX);
+            return Err(FnvError::InvalidLength { expected, actual });
+        }
+        if digest.len() != expected {
+            return Err(FnvError::InvalidLength { expected, actual: digest.len() });
+        }
+
+        FnvTaggedHash::new(algorithm, FnvHashResult::from_slice(digest))
+    }
+}
+
+/// Writes `value` as an unsigned LEB128 varint, returning its length.
+#[cfg(any(feature = "alloc", feature = "serde"))]
+fn write_varint(mut value: u64, out: &mut [u8]) -> usize {
+    let mut len = 0;
+    while value >= 0x80 {
+        out[len] = value as u8 | 0x80;
+        value >>= 7;
+        len += 1;
+    }
+    out[len] = value as u8;
+
+    len + 1
+}
+
+/// Reads an unsigned LEB128 varint of at most 9 bytes, as multiformats allows.
+fn read_varint(bytes: &[u8]) -> Result<(u64, usize), FnvError> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= ((byte & 0x7F) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+
+    Err(FnvError::InvalidMultihash)
+}
+
+impl fmt::Display for FnvTaggedHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.algorithm, self.hash)
+    }
+}
+
+impl FromStr for FnvTaggedHash {
+    type Err = FnvError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, hash) = s.split_once(':').ok_or(FnvError::UnknownAlgorithm)?;
+        FnvTaggedHash::new(name.parse()?, hash.parse()?)
+    }
+}
@@ -1,5 +1,22 @@
+use std::format;
+use std::string::ToString;
 use std::hash::Hasher;
-use crate::Fnv64;
+#[cfg(feature = "std")]
+use std::hash::BuildHasher;
+use std::vec::Vec;
+use crate::{Fnv64, FnvHasher, FnvHashResult, hash_folded};
+#[cfg(feature = "std")]
+use crate::{Fnv32HashMap, Fnv64HashMap, Fnv64BuildHasher, Fnv128HashSet};
+#[cfg(feature = "std")]
+use crate::{FnvRandomState, FnvRandomHashMap, FnvRandomHashSet};
+use crate::{Fnv32, Fnv128, Fnv16, Fnv24, Fnv48, Fnv56};
+use crate::{Fnv0_32, Fnv0_64, Fnv0_128, Fnv1_32, Fnv1_64, Fnv1_128};
+use crate::{FnvDigest, FnvError};
+use crate::wide_uint::WideUint;
+use crate::{Fnv256, Fnv512, Fnv1024};
+#[cfg(feature = "std")]
+use crate::{Fnv512HashSet, Fnv1024HashMap};
+use crate::{Fnv0_256, Fnv0_512, Fnv0_1024, Fnv1_256, Fnv1_512, Fnv1_1024};
 
 fn fnv1a(bytes: &[u8]) -> u64 {
     let mut hasher = Fnv64::default();
@@ -221,3 +238,1055 @@ fn basic_tests() {
     assert_eq!(fnv1a(&repeat_500(b"~")), 0xc1af12bdfe16b5b5);
     assert_eq!(fnv1a(&repeat_500(b"\x7f")), 0x39e9f18f2f85e221);
 }
+
+#[test]
+fn fnv1_tests() {
+    assert_eq!(Fnv1_32::hash(b"").to_string(), "811C9DC5");
+    assert_eq!(Fnv1_32::hash(b"a").to_string(), "050C5D7E");
+    assert_eq!(Fnv1_32::hash(b"foobar").to_string(), "31F0B262");
+    assert_eq!(Fnv1_32::hash(b"chongo was here!\n").to_string(), "DD002F35");
+    assert_eq!(Fnv1_64::hash(b"").to_string(), "CBF29CE484222325");
+    assert_eq!(Fnv1_64::hash(b"a").to_string(), "AF63BD4C8601B7BE");
+    assert_eq!(Fnv1_64::hash(b"foobar").to_string(), "340D8765A4DDA9C2");
+    assert_eq!(Fnv1_64::hash(b"chongo was here!\n").to_string(), "E0ACA20B624E4235");
+    assert_eq!(Fnv1_128::hash(b"").to_string(), "6C62272E07BB014262B821756295C58D");
+    assert_eq!(Fnv1_128::hash(b"a").to_string(), "D228CB69101A8CAF78912B704E4A141E");
+    assert_eq!(Fnv1_128::hash(b"foobar").to_string(), "7896BFEA9C3C64BF6DC58353D2C293AA");
+    assert_eq!(Fnv1_128::hash(b"chongo was here!\n").to_string(), "40AB469AF9CF0FE57236785215BEEE65");
+}
+
+#[test]
+fn fnv0_tests() {
+    assert_eq!(Fnv0_32::hash(b"").to_string(), "00000000");
+    assert_eq!(Fnv0_32::hash(b"a").to_string(), "00000061");
+    assert_eq!(Fnv0_32::hash(b"foobar").to_string(), "B74BB5EF");
+    assert_eq!(Fnv0_32::hash(b"chongo was here!\n").to_string(), "0FDE7BAE");
+    assert_eq!(Fnv0_64::hash(b"").to_string(), "0000000000000000");
+    assert_eq!(Fnv0_64::hash(b"a").to_string(), "0000000000000061");
+    assert_eq!(Fnv0_64::hash(b"foobar").to_string(), "0B91AE3F7CCDC5EF");
+    assert_eq!(Fnv0_64::hash(b"chongo was here!\n").to_string(), "B382ADB5BB48EB6E");
+    assert_eq!(Fnv0_128::hash(b"").to_string(), "00000000000000000000000000000000");
+    assert_eq!(Fnv0_128::hash(b"a").to_string(), "00000000000000000000000000000061");
+    assert_eq!(Fnv0_128::hash(b"foobar").to_string(), "9438FF4BEA000000000120AB5188D04F");
+    assert_eq!(Fnv0_128::hash(b"chongo was here!\n").to_string(), "4FEB92BE60F1740C74F4391A65CACC1E");
+}
+
+#[test]
+fn fnv1_bigint_tests() {
+    assert_eq!(Fnv1_256::hash(b"a").to_string(), "63323FB0F35303EC28DC561D0A33BDFA4DE6A99B7266494F6183B2716811381E");
+    assert_eq!(Fnv1_256::hash(b"foobar").to_string(), "B055EA2F2CC3908DDDB794C02D3889DC32453DAD5AE35B753AC86C6C2AC80D72");
+    assert_eq!(Fnv1_512::hash(b"a").to_string(), "E43A992DC8FC5AD7DE493E3D696D6F85D64326EC28000000000000000011986F90C2532CAF5BE7D88291BAA894A395225328B196BD6A8A643FE12CD87B282BDE");
+    assert_eq!(Fnv1_512::hash(b"foobar").to_string(), "B0EC738D9C6FD969D05F0B35F6C0EFFD20209465290000004BF99F58EE4196AFB9700E20110830FEA5396B76280E47FD022B6E81331CA1A9CF6FAF7123C3FC56");
+    assert_eq!(Fnv1_1024::hash(b"a").to_string(), "000000000000000098D7C19FBCE653DF221B9F717D3490FF95CA87FDAEF30D1B823372F85B24A372F50E380000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007685CD81A491DBCCC21AD06648D09A5C8CF5A78482054E91470B33DDE77252CAEF665F6");
+    assert_eq!(Fnv1_1024::hash(b"foobar").to_string(), "00000631175FA7AE643AD08723D312C9FD024ADB91F77F6B19587197A22BCDF23727166C3E596993CF5A8D00000000000000000000000000000000000000000000000000000000000000000000000000000000000000004270D11EF418EF08B8A49E1E825E547EB39937F819222F3B7FC92A0E470790088882A53CA30E08F65C");
+}
+
+#[test]
+fn fnv0_bigint_tests() {
+    assert_eq!(Fnv0_256::hash(b"a").to_string(), "0000000000000000000000000000000000000000000000000000000000000061");
+    assert_eq!(Fnv0_256::hash(b"foobar").to_string(), "0000000000075A621EF5AA00000000000000000000000000000209D27D06710F");
+    assert_eq!(Fnv0_512::hash(b"a").to_string(), "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000061");
+    assert_eq!(Fnv0_512::hash(b"foobar").to_string(), "000000000000000000000000000000066C927EDF9A00000000000000000000000000000000000000000000000000000000000000000000000001B8C2BBBC218F");
+    assert_eq!(Fnv0_1024::hash(b"a").to_string(), "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000061");
+    assert_eq!(Fnv0_1024::hash(b"foobar").to_string(), "000000000000000000000000000000000000000000000000000000000000000000000000000B86C3DBB99E000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000039348798173B7");
+}
+
+#[test]
+fn variant_update_matches_hash() {
+    let mut hasher = Fnv1_64::new();
+    hasher.update(b"foo");
+    hasher.update(b"bar");
+    assert_eq!(hasher.finalize().as_bytes(), Fnv1_64::hash(b"foobar").as_bytes());
+
+    let mut hasher = Fnv0_64::new();
+    hasher.update(b"foo");
+    hasher.update(b"bar");
+    assert_eq!(hasher.finalize().as_bytes(), Fnv0_64::hash(b"foobar").as_bytes());
+}
+
+#[test]
+fn folded_tests() {
+    assert_eq!(Fnv16::hash(b"").to_string(), "1CD9");
+    assert_eq!(Fnv16::hash(b"foobar").to_string(), "46F4");
+    assert_eq!(Fnv24::hash(b"").to_string(), "1C9D44");
+    assert_eq!(Fnv24::hash(b"foobar").to_string(), "9CF9D7");
+    assert_eq!(Fnv48::hash(b"").to_string(), "9CE48422E8D7");
+    assert_eq!(Fnv48::hash(b"foobar").to_string(), "4171F739E27C");
+    assert_eq!(Fnv56::hash(b"").to_string(), "F29CE4842223EE");
+    assert_eq!(Fnv56::hash(b"foobar").to_string(), "944171F739676D");
+
+    let mut hasher = Fnv24::new();
+    hasher.update(b"foo");
+    hasher.update(b"bar");
+    assert_eq!(hasher.finalize().as_bytes(), Fnv24::hash(b"foobar").as_bytes());
+}
+
+#[test]
+fn hash_folded_tests() {
+    assert_eq!(hash_folded(b"foobar", 1).to_string(), "00");
+    assert_eq!(hash_folded(b"foobar", 7).to_string(), "1A");
+    assert_eq!(hash_folded(b"foobar", 8).to_string(), "91");
+    assert_eq!(hash_folded(b"foobar", 20).to_string(), "0CF291");
+    assert_eq!(hash_folded(b"foobar", 24).as_bytes(), Fnv24::hash(b"foobar").as_bytes());
+    assert_eq!(hash_folded(b"foobar", 33).to_string(), "01B5F34750");
+    assert_eq!(hash_folded(b"foobar", 100).to_string(), "02793C64BF6F0D3597B9078E7E");
+    assert_eq!(hash_folded(b"foobar", 127).to_string(), "343E1662793C64BF6F0D3597BA446F18");
+    assert_eq!(hash_folded(b"foobar", 128).to_string(), "343E1662793C64BF6F0D3597BA446F18");
+}
+
+#[test]
+fn hash_folded_bigint_tests() {
+    assert_eq!(hash_folded(b"foobar", 200).to_string(), "AD4F0F81C02D3889DC32453DAD5AE35B753B11FCFAAB9F5885");
+}
+
+#[test]
+#[should_panic]
+fn xor_fold_wider_than_hash() {
+    Fnv32::hash(b"foobar").xor_fold(33);
+}
+
+#[test]
+fn range_mapping_tests() {
+    assert_eq!(Fnv32::hash(b"foobar").lazy_mod(1000), 720);
+    assert_eq!(Fnv32::hash(b"foobar").retry_mod(1000), Ok(720));
+    assert_eq!(Fnv64::hash(b"foobar").lazy_mod(3), 0);
+    assert_eq!(Fnv128::hash(b"foobar").lazy_mod(1000), 88);
+    assert_eq!(Fnv128::hash(b"foobar").retry_mod(9223372036854788153), Ok(7134353026101421503));
+
+    // hashes above the retry level are re-mixed before the modulo
+    assert_eq!(Fnv32::hash(b"foobar").lazy_mod(0x80000001), 1067252071);
+    assert_eq!(Fnv32::hash(b"foobar").retry_mod(0x80000001), Ok(1328993932));
+    assert_eq!(Fnv64::hash(b"foobar").lazy_mod(9223372036854788153), 402018224477648815);
+    assert_eq!(Fnv64::hash(b"foobar").retry_mod(9223372036854788153), Ok(19625782639702621));
+
+    // ranges wider than the hash leave it untouched
+    assert_eq!(Fnv32::hash(b"foobar").retry_mod(u64::MAX), Ok(0xBF9CF968));
+
+    let mut hasher = Fnv64::new();
+    hasher.update(b"a");
+    assert_eq!(hasher.finalize_lazy_mod(9223372036854788153), 3414815163700853843);
+    assert_eq!(hasher.finalize_retry_mod(9223372036854788153), Ok(7001216474233364848));
+}
+
+#[test]
+fn range_mapping_bigint_tests() {
+    assert_eq!(Fnv256::hash(b"foobar").lazy_mod(1000), 664);
+    assert_eq!(Fnv256::hash(b"foobar").retry_mod(4294967290), Ok(3701736334));
+    assert_eq!(Fnv512::hash(b"a").retry_mod(3), Ok(0));
+    assert_eq!(Fnv1024::hash(b"foobar").retry_mod(9223372036854788153), Ok(8145242032899518909));
+}
+
+#[test]
+fn retry_mod_folded_hash() {
+    // the retry method only re-mixes full FNV widths
+    assert_eq!(Fnv24::hash(b"foobar").retry_mod(1000), Err(FnvError::UnsupportedWidth { bits: 24 }));
+    assert_eq!(Fnv56::new().finalize_retry_mod(1000), Err(FnvError::UnsupportedWidth { bits: 56 }));
+    assert_eq!(Fnv32::hash(b"foobar").xor_fold(20).retry_mod(1000), Err(FnvError::UnsupportedWidth { bits: 24 }));
+    let hash: FnvHashResult = "D0".parse().unwrap();
+    assert_eq!(hash.retry_mod(52), Err(FnvError::UnsupportedWidth { bits: 8 }));
+}
+
+#[test]
+fn hash_result_formatting() {
+    let hash: FnvHashResult = Fnv64::hash(b"Hash this!testing123").into();
+    assert_eq!(hash.len(), 8);
+    assert_eq!(hash.as_bytes(), [173, 40, 8, 208, 193, 90, 102, 62]);
+    #[cfg(feature = "alloc")]
+    assert_eq!(hash.as_hex(), "AD2808D0C15A663E");
+    assert_eq!(format!("{}", hash), "AD2808D0C15A663E");
+    assert_eq!(format!("{:X}", hash), "AD2808D0C15A663E");
+    assert_eq!(format!("{:x}", hash), "ad2808d0c15a663e");
+    assert_eq!(format!("{:?}", hash), "FnvHashResult(AD2808D0C15A663E)");
+}
+
+#[test]
+fn digest_tests() {
+    let hash = Fnv64::hash(b"Hash this!testing123");
+    assert_eq!(hash.len(), 8);
+    assert_eq!(hash.as_bytes(), &[173, 40, 8, 208, 193, 90, 102, 62]);
+    #[cfg(feature = "alloc")]
+    assert_eq!(hash.as_hex(), "AD2808D0C15A663E");
+    assert_eq!(format!("{}", hash), "AD2808D0C15A663E");
+    assert_eq!(format!("{:x}", hash), "ad2808d0c15a663e");
+    assert_eq!(format!("{:?}", hash), "FnvDigest(AD2808D0C15A663E)");
+
+    assert_eq!(u64::from(hash), 0xAD2808D0C15A663E);
+    assert_eq!(u32::from(Fnv32::hash(b"foobar")), 0xBF9CF968);
+    assert_eq!(u128::from(Fnv128::hash(b"foobar")), 0x343E1662793C64BF6F0D3597BA446F18);
+
+    let result: FnvHashResult = hash.into();
+    assert_eq!(result.as_bytes(), hash.as_bytes());
+
+    let mut hasher = Fnv64::new();
+    hasher.update(b"Hash this!");
+    hasher.update(b"testing123");
+    assert_eq!(hasher.finalize(), hash);
+    assert_ne!(Fnv64::hash(b"foobar"), hash);
+}
+
+#[test]
+fn const_hash_tests() {
+    const FOOBAR_32: u32 = Fnv32::const_hash(b"foobar");
+    const FOOBAR_64: u64 = Fnv64::const_hash(b"foobar");
+    const FOOBAR_128: u128 = Fnv128::const_hash(b"foobar");
+    const FOOBAR_1_64: u64 = Fnv1_64::const_hash(b"foobar");
+    const FOOBAR_0_64: u64 = Fnv0_64::const_hash(b"foobar");
+    const FOOBAR_24: u32 = Fnv24::const_hash(b"foobar");
+
+    assert_eq!(FOOBAR_32, u32::from(Fnv32::hash(b"foobar")));
+    assert_eq!(FOOBAR_64, 0x85944171f73967e8);
+    assert_eq!(FOOBAR_128, u128::from(Fnv128::hash(b"foobar")));
+    assert_eq!(FOOBAR_1_64, 0x340d8765a4dda9c2);
+    assert_eq!(FOOBAR_0_64, 0x0b91ae3f7ccdc5ef);
+    assert_eq!(FOOBAR_24, 0x9CF9D7);
+    assert_eq!(Fnv16::const_hash(b"foobar"), 0x46F4);
+
+    let id = match fnv1a(b"foobar") {
+        FOOBAR_64 => 1,
+        _ => 0,
+    };
+    assert_eq!(id, 1);
+}
+
+#[test]
+fn const_hash_bigint_tests() {
+    const FOOBAR_256: FnvDigest<32> = Fnv256::const_hash(b"foobar");
+    const FOOBAR_512: FnvDigest<64> = Fnv512::const_hash(b"foobar");
+    const FOOBAR_1024: FnvDigest<128> = Fnv1_1024::const_hash(b"foobar");
+
+    assert_eq!(FOOBAR_256, Fnv256::hash(b"foobar"));
+    assert_eq!(FOOBAR_512, Fnv512::hash(b"foobar"));
+    assert_eq!(FOOBAR_1024, Fnv1_1024::hash(b"foobar"));
+}
+
+/// FNV computed with `crypto_bigint`'s full-width multiplication, as the wide hashers used to.
+#[cfg(feature = "bigint")]
+fn crypto_bigint_fnv<const LIMBS: usize>(
+    bytes: &[u8],
+    prime: crypto_bigint::Uint<LIMBS>,
+    offset: crypto_bigint::Uint<LIMBS>,
+    fnv1a: bool,
+) -> crypto_bigint::Uint<LIMBS> {
+    use crypto_bigint::Uint;
+
+    bytes.iter().fold(offset, |hash, byte| {
+        if fnv1a {
+            (hash ^ Uint::from_u8(*byte)).wrapping_mul(&prime)
+        } else {
+            hash.wrapping_mul(&prime) ^ Uint::from_u8(*byte)
+        }
+    })
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn wide_hashers_match_crypto_bigint() {
+    use crypto_bigint::{U256, U512, U1024};
+
+    // the primes are 2^k + b, and the offset bases are the FNV-0 hash of the spec's signature
+    let prime_256 = U256::ONE.shl_vartime(168).wrapping_add(&U256::from_u64(0x163));
+    let prime_512 = U512::ONE.shl_vartime(344).wrapping_add(&U512::from_u64(0x157));
+    let prime_1024 = U1024::ONE.shl_vartime(680).wrapping_add(&U1024::from_u64(0x18D));
+    let signature = b"chongo <Landon Curt Noll> /\\../\\";
+    let offset_256 = crypto_bigint_fnv(signature, prime_256, U256::ZERO, false);
+    let offset_512 = crypto_bigint_fnv(signature, prime_512, U512::ZERO, false);
+    let offset_1024 = crypto_bigint_fnv(signature, prime_1024, U1024::ZERO, false);
+
+    let inputs: [&[u8]; 4] = [b"", b"a", b"foobar", &repeat_500(b"chongo was here!\n")];
+    for bytes in inputs {
+        assert_eq!(U256::from(Fnv256::hash(bytes)), crypto_bigint_fnv(bytes, prime_256, offset_256, true));
+        assert_eq!(U512::from(Fnv512::hash(bytes)), crypto_bigint_fnv(bytes, prime_512, offset_512, true));
+        assert_eq!(U1024::from(Fnv1024::hash(bytes)), crypto_bigint_fnv(bytes, prime_1024, offset_1024, true));
+        assert_eq!(U256::from(Fnv1_256::hash(bytes)), crypto_bigint_fnv(bytes, prime_256, offset_256, false));
+        assert_eq!(U512::from(Fnv1_512::hash(bytes)), crypto_bigint_fnv(bytes, prime_512, offset_512, false));
+        assert_eq!(U1024::from(Fnv1_1024::hash(bytes)), crypto_bigint_fnv(bytes, prime_1024, offset_1024, false));
+        assert_eq!(U1024::from(Fnv0_1024::hash(bytes)), crypto_bigint_fnv(bytes, prime_1024, U1024::ZERO, false));
+    }
+}
+
+#[test]
+fn hasher_tests() {
+    fn finish<H: Hasher + FnvHasher>(bytes: &[u8]) -> u64 {
+        let mut hasher = H::new();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    assert_eq!(finish::<Fnv32>(b"foobar"), 0xBF9CF968_BF9CF968);
+    assert_eq!(finish::<Fnv64>(b"foobar"), 0x85944171F73967E8);
+    assert_eq!(finish::<Fnv128>(b"foobar"), 0x343E1662793C64BF ^ 0x6F0D3597BA446F18);
+
+    // the top byte, where std's `HashMap` takes its control bits from, must vary with the key
+    let top_bytes: Vec<u8> = (0..16u8).map(|key| (finish::<Fnv32>(&[key]) >> 56) as u8).collect();
+    assert!(top_bytes.iter().any(|byte| *byte != top_bytes[0]));
+}
+
+#[test]
+fn hasher_bigint_tests() {
+    let mut hasher = Fnv256::new();
+    hasher.write(b"foobar");
+    assert_eq!(hasher.finish(), 0xB055EA2F306CADAD ^ 0x4F0F81C02D3889DC ^ 0x32453DAD5AE35B75 ^ 0x3BA1A91084AF3428);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hasher_collection_tests() {
+    let mut map = Fnv32HashMap::default();
+    map.insert(1, "one");
+    map.insert(2, "two");
+    assert_eq!(map.get(&1), Some(&"one"));
+
+    let mut set = Fnv128HashSet::default();
+    set.insert("foo");
+    assert!(set.contains("foo"));
+    assert!(!set.contains("bar"));
+
+    let mut map: Fnv64HashMap<_, _> = Fnv64HashMap::with_capacity_and_hasher(10, Fnv64BuildHasher::default());
+    map.insert(1, "one");
+    assert_eq!(map.get(&1), Some(&"one"));
+
+    let mut map = Fnv1024HashMap::default();
+    map.insert(1, "one");
+    assert_eq!(map.get(&1), Some(&"one"));
+
+    let mut set = Fnv512HashSet::default();
+    set.insert(1);
+    assert!(set.contains(&1));
+}
+
+#[cfg(feature = "std")]
+fn hash_with<B: BuildHasher>(build_hasher: &B, bytes: &[u8]) -> u64 {
+    let mut hasher = build_hasher.build_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn random_state_tests() {
+    let seeded = FnvRandomState::with_seed(42);
+    assert_eq!(hash_with(&seeded, b"foobar"), hash_with(&FnvRandomState::with_seed(42), b"foobar"));
+    assert_ne!(hash_with(&seeded, b"foobar"), hash_with(&FnvRandomState::with_seed(43), b"foobar"));
+    assert_ne!(hash_with(&seeded, b"foobar"), hash_with(&Fnv64BuildHasher::default(), b"foobar"));
+
+    // every random state gets its own offset basis
+    assert_ne!(hash_with(&FnvRandomState::new(), b"foobar"), hash_with(&FnvRandomState::new(), b"foobar"));
+
+    // an empty input hashes to the offset basis itself, which is never zero
+    assert_ne!(hash_with(&FnvRandomState::with_seed(0), b""), 0);
+
+    // the offset basis is secret, so `Debug` leaves it out
+    assert_eq!(format!("{:?}", seeded), "FnvRandomState { .. }");
+
+    let mut map = FnvRandomHashMap::default();
+    map.insert(1, "one");
+    map.insert(2, "two");
+    assert_eq!(map.get(&1), Some(&"one"));
+
+    let mut set = FnvRandomHashSet::with_hasher(FnvRandomState::with_seed(7));
+    set.insert("foo");
+    assert!(set.contains("foo"));
+    assert!(!set.contains("bar"));
+}
+
+/// Edge cases plus pseudo-random values for differential tests of the wide arithmetic.
+fn wide_samples<const LIMBS: usize>() -> Vec<WideUint<LIMBS>> {
+    let mut one = WideUint::ZERO;
+    one.0[0] = 1;
+    let mut top_bit = WideUint::ZERO;
+    top_bit.0[LIMBS - 1] = 1 << 63;
+
+    let mut samples = std::vec![WideUint::ZERO, one, top_bit, WideUint::MAX];
+    samples.extend((0u32..256).map(|i| {
+        WideUint::from_be_slice(&Fnv1024::hash(i.to_le_bytes()).as_bytes()[..LIMBS * 8])
+    }));
+    samples
+}
+
+#[test]
+fn sparse_prime_mul_matches_full_mul() {
+    use crate::consts::*;
+
+    for value in wide_samples::<4>() {
+        assert_eq!(value.wrapping_mul_sparse(&FNV_PRIME_256_SPARSE), value.wrapping_mul(&FNV_PRIME_256));
+    }
+    for value in wide_samples::<8>() {
+        assert_eq!(value.wrapping_mul_sparse(&FNV_PRIME_512_SPARSE), value.wrapping_mul(&FNV_PRIME_512));
+    }
+    for value in wide_samples::<16>() {
+        assert_eq!(value.wrapping_mul_sparse(&FNV_PRIME_1024_SPARSE), value.wrapping_mul(&FNV_PRIME_1024));
+    }
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn sparse_prime_mul_matches_crypto_bigint() {
+    use crate::consts::*;
+    use crypto_bigint::{U256, U512, U1024};
+
+    fn as_digest<const LIMBS: usize, const N: usize>(value: WideUint<LIMBS>) -> FnvDigest<N> {
+        FnvDigest(value.to_be_bytes())
+    }
+
+    let prime_256 = U256::from(as_digest(FNV_PRIME_256));
+    let prime_512 = U512::from(as_digest(FNV_PRIME_512));
+    let prime_1024 = U1024::from(as_digest(FNV_PRIME_1024));
+
+    for value in wide_samples::<4>() {
+        let expected = U256::from(as_digest(value)).wrapping_mul(&prime_256);
+        assert_eq!(U256::from(as_digest(value.wrapping_mul_sparse(&FNV_PRIME_256_SPARSE))), expected);
+    }
+    for value in wide_samples::<8>() {
+        let expected = U512::from(as_digest(value)).wrapping_mul(&prime_512);
+        assert_eq!(U512::from(as_digest(value.wrapping_mul_sparse(&FNV_PRIME_512_SPARSE))), expected);
+    }
+    for value in wide_samples::<16>() {
+        let expected = U1024::from(as_digest(value)).wrapping_mul(&prime_1024);
+        assert_eq!(U1024::from(as_digest(value.wrapping_mul_sparse(&FNV_PRIME_1024_SPARSE))), expected);
+    }
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+fn fnv_parameters_tests() {
+    use crate::{consts::*, fnv_parameters};
+    use crypto_bigint::{U256, U512, U1024};
+
+    fn wide<const LIMBS: usize, const N: usize>(value: WideUint<LIMBS>) -> FnvDigest<N> {
+        FnvDigest(value.to_be_bytes())
+    }
+
+    let expected = [
+        (32, U1024::from_u32(FNV_PRIME_32), U1024::from_u32(FNV_OFFSET_32)),
+        (64, U1024::from_u64(FNV_PRIME_64), U1024::from_u64(FNV_OFFSET_64)),
+        (128, U1024::from_u128(FNV_PRIME_128), U1024::from_u128(FNV_OFFSET_128)),
+        (256, U256::from(wide(FNV_PRIME_256)).resize(), U256::from(wide(FNV_OFFSET_256)).resize()),
+        (512, U512::from(wide(FNV_PRIME_512)).resize(), U512::from(wide(FNV_OFFSET_512)).resize()),
+        (1024, U1024::from(wide(FNV_PRIME_1024)), U1024::from(wide(FNV_OFFSET_1024))),
+    ];
+    for (bits, prime, offset_basis) in expected {
+        let parameters = fnv_parameters(bits).unwrap();
+        assert_eq!(parameters.bits, bits);
+        assert_eq!(parameters.prime, prime);
+        assert_eq!(parameters.offset_basis, offset_basis);
+    }
+
+    // only powers of two from 32 through 1024 bits are defined
+    assert_eq!(fnv_parameters(16), None);
+    assert_eq!(fnv_parameters(48), None);
+    // no prime meets the spec's criteria at 2048 bits
+    assert_eq!(fnv_parameters(2048), None);
+}
+
+#[test]
+#[cfg(feature = "bigint")]
+#[ignore = "searches 4096-bit candidates, which takes several seconds in debug builds"]
+fn fnv_parameters_4096_tests() {
+    // no prime meets the spec's criteria at 4096 bits either
+    assert_eq!(crate::fnv_parameters(4096), None);
+}
+
+#[test]
+fn generic_params_tests() {
+    use crate::{Fnv, FnvParams, FnvVariant};
+
+    struct Standard64;
+    impl FnvParams for Standard64 {
+        type Word = u64;
+        const PRIME: u64 = 0x100_000001B3;
+        const OFFSET_BASIS: u64 = 0xCBF29CE4_84222325;
+        const VARIANT: FnvVariant = FnvVariant::Fnv1a;
+    }
+
+    struct CustomOffset32;
+    impl FnvParams for CustomOffset32 {
+        type Word = u32;
+        const PRIME: u32 = 0x1000193;
+        const OFFSET_BASIS: u32 = 0x12345678;
+        const VARIANT: FnvVariant = FnvVariant::Fnv1;
+    }
+
+    struct Zero32;
+    impl FnvParams for Zero32 {
+        type Word = u32;
+        const PRIME: u32 = 0x1000193;
+        const OFFSET_BASIS: u32 = 0x12345678;
+        const VARIANT: FnvVariant = FnvVariant::Fnv0;
+    }
+
+    struct CustomPrime256;
+    impl FnvParams for CustomPrime256 {
+        type Word = WideUint<4>;
+        const PRIME: WideUint<4> = WideUint([3, 0, 0, 0]);
+        const OFFSET_BASIS: WideUint<4> = WideUint([1, 0, 0, 0]);
+        const VARIANT: FnvVariant = FnvVariant::Fnv1a;
+    }
+
+    assert_eq!(Fnv::<Standard64>::hash(b"foobar"), Fnv64::hash(b"foobar"));
+    assert_eq!(Fnv::<Standard64>::hash(repeat_500(b"foobar")), Fnv64::hash(repeat_500(b"foobar")));
+
+    // FNV-1 from 0x12345678: (0x12345678 * p) ^ 'a'
+    assert_eq!(u32::from(Fnv::<CustomOffset32>::hash(b"a")), 0x12345678u32.wrapping_mul(0x1000193) ^ 0x61);
+
+    // FNV-0 ignores the offset basis
+    assert_eq!(Fnv::<Zero32>::hash(b"foobar"), Fnv0_32::hash(b"foobar"));
+
+    // ((1 ^ 'a') * 3 ^ 'b') * 3
+    let hash = Fnv::<CustomPrime256>::hash(b"ab");
+    assert_eq!(&hash.as_bytes()[24..], &((((1 ^ 0x61) * 3) ^ 0x62) * 3u64).to_be_bytes());
+    assert!(hash.as_bytes()[..24].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn algorithm_tests() {
+    use crate::{DynFnvHasher, FnvAlgorithm, FnvError};
+    use std::string::ToString;
+
+    assert_eq!(FnvAlgorithm::ALL.len(), 22);
+    for algorithm in FnvAlgorithm::ALL {
+        assert_eq!(algorithm.to_string().parse::<FnvAlgorithm>(), Ok(*algorithm));
+        assert_eq!(algorithm.hash(b"foobar").len() * 8, algorithm.bits() as usize);
+
+        let mut hasher = DynFnvHasher::new(*algorithm);
+        hasher.update(b"foo");
+        hasher.update(b"bar");
+        assert_eq!(hasher.algorithm(), *algorithm);
+        assert_eq!(hasher.finalize(), algorithm.hash(b"foobar"));
+    }
+
+    assert_eq!("FNV1A-64".parse(), Ok(FnvAlgorithm::Fnv1a64));
+    assert_eq!("fnv1a64".parse::<FnvAlgorithm>(), Err(FnvError::UnknownAlgorithm));
+    assert_eq!("fnv1-16".parse::<FnvAlgorithm>(), Err(FnvError::UnknownAlgorithm));
+
+    assert_eq!(FnvAlgorithm::Fnv1a24.hash(b"foobar").as_bytes(), Fnv24::hash(b"foobar").as_bytes());
+    assert_eq!(FnvAlgorithm::Fnv1_1024.hash(b"foobar").as_bytes(), Fnv1_1024::hash(b"foobar").as_bytes());
+    assert_eq!(FnvAlgorithm::Fnv0_64.new_hasher().finalize().as_bytes(), &[0; 8]);
+}
+
+#[test]
+fn checkpoint_tests() {
+    use crate::{DynFnvHasher, FnvAlgorithm, FnvDigest, FnvError};
+
+    let mut prefix = Fnv64::new();
+    prefix.update(b"foo");
+    let mut foobar = prefix;
+    foobar.update(b"bar");
+    let mut foobaz = prefix;
+    foobaz.update(b"baz");
+    assert_eq!(foobar.finalize(), Fnv64::hash(b"foobar"));
+    assert_eq!(foobaz.finalize(), Fnv64::hash(b"foobaz"));
+    assert_ne!(foobar, foobaz);
+    assert_eq!(foobar, Fnv64::from_state(Fnv64::hash(b"foobar")));
+
+    let mut hasher = Fnv1024::new();
+    hasher.update(b"foo");
+    let state: [u8; 128] = hasher.state().to_bytes();
+    let mut resumed = Fnv1024::from_state(FnvDigest::from(state));
+    assert_eq!(resumed, hasher);
+    resumed.update(b"bar");
+    assert_eq!(resumed.finalize(), Fnv1024::hash(b"foobar"));
+
+    // the folded hashers export the unfolded state
+    let mut hasher = Fnv24::new();
+    hasher.update(b"foo");
+    assert_eq!(hasher.state(), Fnv32::hash(b"foo"));
+    let mut resumed = Fnv24::from_state(hasher.state());
+    resumed.update(b"bar");
+    assert_eq!(resumed.finalize(), Fnv24::hash(b"foobar"));
+
+    let mut hasher = Fnv1_128::new();
+    hasher.update(b"foobar");
+    assert_eq!(hasher.finalize_reset(), Fnv1_128::hash(b"foobar"));
+    assert_eq!(hasher, Fnv1_128::new());
+    hasher.update(b"foo");
+    hasher.reset();
+    assert_eq!(hasher.finalize(), Fnv1_128::hash(b""));
+
+    for algorithm in FnvAlgorithm::ALL {
+        let mut hasher = DynFnvHasher::new(*algorithm);
+        hasher.update(b"foo");
+        let state = hasher.state();
+        let mut resumed = DynFnvHasher::from_state(*algorithm, state.as_bytes()).unwrap();
+        assert_eq!(resumed, hasher);
+        resumed.update(b"bar");
+        assert_eq!(resumed.finalize_reset(), algorithm.hash(b"foobar"));
+        assert_eq!(resumed, DynFnvHasher::new(*algorithm));
+        assert_eq!(
+            DynFnvHasher::from_state(*algorithm, &state.as_bytes()[1..]),
+            Err(FnvError::InvalidLength { expected: state.len(), actual: state.len() - 1 })
+        );
+    }
+}
+
+#[test]
+fn unupdate_tests() {
+    use crate::{consts::*, DynFnvHasher, Fnv, FnvAlgorithm, FnvParams, FnvVariant, WideUint};
+
+    assert_eq!(FNV_PRIME_32.wrapping_mul(FNV_PRIME_INVERSE_32), 1);
+    assert_eq!(FNV_PRIME_64.wrapping_mul(FNV_PRIME_INVERSE_64), 1);
+    assert_eq!(FNV_PRIME_128.wrapping_mul(FNV_PRIME_INVERSE_128), 1);
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    assert_eq!(FNV_PRIME_256.wrapping_mul(&FNV_PRIME_INVERSE_256), WideUint::from_be_slice(&one));
+    let mut one = [0u8; 64];
+    one[63] = 1;
+    assert_eq!(FNV_PRIME_512.wrapping_mul(&FNV_PRIME_INVERSE_512), WideUint::from_be_slice(&one));
+    let mut one = [0u8; 128];
+    one[127] = 1;
+    assert_eq!(FNV_PRIME_1024.wrapping_mul(&FNV_PRIME_INVERSE_1024), WideUint::from_be_slice(&one));
+
+    for algorithm in FnvAlgorithm::ALL {
+        let mut hasher = DynFnvHasher::new(*algorithm);
+        hasher.update(b"foobar");
+        hasher.unupdate(b"bar");
+        assert_eq!(hasher.finalize(), algorithm.hash(b"foo"));
+        hasher.unupdate(b"foo");
+        assert_eq!(hasher, DynFnvHasher::new(*algorithm));
+    }
+
+    // dropping records from the end, most recent first
+    let records: [&[u8]; 3] = [b"the quick", b" brown", b" fox"];
+    let mut hasher = Fnv128::new();
+    records.iter().for_each(|record| hasher.update(record));
+    for i in (0..records.len()).rev() {
+        hasher.unupdate(records[i]);
+        assert_eq!(hasher.finalize(), Fnv128::hash(records[..i].concat()));
+    }
+
+    // custom parameters fall back to inverting the prime on every call
+    struct Custom32;
+
+    impl FnvParams for Custom32 {
+        type Word = u32;
+        const PRIME: u32 = 0x01000193;
+        const OFFSET_BASIS: u32 = 0x12345678;
+        const VARIANT: FnvVariant = FnvVariant::Fnv1;
+    }
+
+    let mut hasher = Fnv::<Custom32>::new();
+    hasher.update(b"foobar");
+    hasher.unupdate(b"foobar");
+    assert_eq!(hasher, Fnv::<Custom32>::new());
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn rustcrypto_digest_tests() {
+    use digest::Digest;
+    use std::boxed::Box;
+
+    fn digest_in_parts<D: Digest>() -> Box<[u8]> {
+        let mut hasher = D::new();
+        Digest::update(&mut hasher, b"foo");
+        Digest::update(&mut hasher, b"bar");
+        Box::from(hasher.finalize().as_slice())
+    }
+
+    assert_eq!(&*digest_in_parts::<Fnv16>(), Fnv16::hash(b"foobar").as_bytes());
+    assert_eq!(&*digest_in_parts::<Fnv56>(), Fnv56::hash(b"foobar").as_bytes());
+    assert_eq!(&*digest_in_parts::<Fnv1_128>(), Fnv1_128::hash(b"foobar").as_bytes());
+    assert_eq!(&*digest_in_parts::<Fnv0_1024>(), Fnv0_1024::hash(b"foobar").as_bytes());
+    assert_eq!(<Fnv256 as Digest>::output_size(), 32);
+    assert_eq!(Fnv512::digest(b"foobar").as_slice(), Fnv512::hash(b"foobar").as_bytes());
+
+    let mut hasher = <Fnv64 as Digest>::new();
+    Digest::update(&mut hasher, b"foobar");
+    assert_eq!(Digest::finalize_reset(&mut hasher).as_slice(), Fnv64::hash(b"foobar").as_bytes());
+    Digest::update(&mut hasher, b"foo");
+    assert_eq!(Digest::finalize(hasher).as_slice(), Fnv64::hash(b"foo").as_bytes());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io_tests() {
+    use crate::{DynFnvHasher, FnvAlgorithm, HashingReader, HashingWriter};
+    use std::io::{self, Read, Write};
+    use std::vec::Vec;
+
+    let data: Vec<u8> = (0..10_000u32).map(|i| i.wrapping_mul(0x9E37_79B9) as u8).collect();
+
+    let mut hasher = Fnv1024::new();
+    io::copy(&mut &data[..], &mut hasher).unwrap();
+    assert_eq!(hasher.finalize(), Fnv1024::hash(&data));
+
+    let mut hasher = Fnv24::new();
+    hasher.write_all(&data).unwrap();
+    assert_eq!(hasher.finalize(), Fnv24::hash(&data));
+
+    let mut hasher = DynFnvHasher::new(FnvAlgorithm::Fnv1_64);
+    io::copy(&mut &data[..], &mut hasher).unwrap();
+    assert_eq!(hasher.finalize().as_bytes(), Fnv1_64::hash(&data).as_bytes());
+
+    let mut reader = HashingReader::<_, Fnv128>::new(&data[..]);
+    let mut copy = Vec::new();
+    reader.read_to_end(&mut copy).unwrap();
+    assert_eq!(copy, data);
+    assert_eq!(reader.finalize().as_bytes(), Fnv128::hash(&data).as_bytes());
+
+    // continue hashing from a hasher that already saw a prefix
+    let mut prefix = Fnv64::new();
+    prefix.update(&data[..100]);
+    let mut reader = HashingReader::with_hasher(&data[100..], prefix);
+    io::copy(&mut reader, &mut io::sink()).unwrap();
+    assert_eq!(*reader.hasher(), Fnv64::from_state(Fnv64::hash(&data)));
+
+    // a writer that takes at most 7 bytes at a time
+    struct Short(Vec<u8>);
+
+    impl Write for Short {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            let len = bytes.len().min(7);
+            self.0.extend_from_slice(&bytes[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = HashingWriter::<_, Fnv32>::new(Short(Vec::new()));
+    assert_eq!(writer.write(&data).unwrap(), 7);
+    assert_eq!(writer.finalize().as_bytes(), Fnv32::hash(&data[..7]).as_bytes());
+    writer.write_all(&data[7..]).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.finalize().as_bytes(), Fnv32::hash(&data).as_bytes());
+    let (inner, hasher) = writer.into_parts();
+    assert_eq!(inner.0, data);
+    assert_eq!(hasher.finalize(), Fnv32::hash(&data));
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn async_io_tests() {
+    use crate::{FnvStreamExt, HashingAsyncReader, HashingAsyncWriter};
+    use bytes::Bytes;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use futures_core::Stream;
+    use std::vec::Vec;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    struct Chunks(std::vec::IntoIter<Bytes>);
+
+    impl Stream for Chunks {
+        type Item = Bytes;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Bytes>> {
+            Poll::Ready(self.0.next())
+        }
+    }
+
+    let data: Vec<u8> = (0..10_000u32).map(|i| i.wrapping_mul(0x9E37_79B9) as u8).collect();
+    let chunks = || Chunks(data.chunks(999).map(Bytes::copy_from_slice).collect::<Vec<_>>().into_iter());
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+    runtime.block_on(async {
+        let mut reader = HashingAsyncReader::<_, Fnv1024>::new(&data[..]);
+        let mut copy = Vec::new();
+        reader.read_to_end(&mut copy).await.unwrap();
+        assert_eq!(copy, data);
+        assert_eq!(reader.finalize().as_bytes(), Fnv1024::hash(&data).as_bytes());
+
+        let mut writer = HashingAsyncWriter::<_, Fnv24>::new(Vec::new());
+        writer.write_all(&data).await.unwrap();
+        writer.shutdown().await.unwrap();
+        assert_eq!(writer.finalize().as_bytes(), Fnv24::hash(&data).as_bytes());
+        let (inner, hasher) = writer.into_parts();
+        assert_eq!(inner, data);
+        assert_eq!(hasher.finalize(), Fnv24::hash(&data));
+
+        assert_eq!(chunks().fnv_hash::<Fnv64>().await.as_bytes(), Fnv64::hash(&data).as_bytes());
+        assert_eq!(chunks().fnv_hash::<Fnv1_512>().await.as_bytes(), Fnv1_512::hash(&data).as_bytes());
+
+        let mut stream = chunks().fnv_hashing::<Fnv128>();
+        let mut copy = Vec::new();
+        while let Some(chunk) = core::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+            copy.extend_from_slice(&chunk);
+        }
+        assert_eq!(copy, data);
+        assert_eq!(stream.finalize().as_bytes(), Fnv128::hash(&data).as_bytes());
+    });
+}
+
+#[test]
+fn hash_result_traits_tests() {
+    use crate::{FnvError, FnvHashResult};
+    use std::collections::HashSet;
+    use std::string::ToString;
+
+    let hash: FnvHashResult = Fnv64::hash(b"foobar").into();
+    assert_eq!(hash.to_string().parse(), Ok(hash));
+    assert_eq!(std::format!("0x{:x}", hash).parse(), Ok(hash));
+    assert_eq!(std::format!("0X{:X}", hash).parse(), Ok(hash));
+    assert_eq!(hash.as_ref(), Fnv64::hash(b"foobar").as_bytes());
+
+    let wide: FnvHashResult = Fnv1024::hash(b"foobar").into();
+    assert_eq!(wide.to_string().parse(), Ok(wide));
+    let folded = hash.xor_fold(24);
+    assert_eq!(folded.to_string().parse(), Ok(folded));
+
+    // different widths never compare equal, even when the value is the same
+    assert_ne!("00ff".parse::<FnvHashResult>(), "ff".parse::<FnvHashResult>());
+    assert!("0001".parse::<FnvHashResult>().unwrap() < "0002".parse().unwrap());
+
+    let set: HashSet<FnvHashResult> = [hash, wide, hash].into_iter().collect();
+    assert_eq!(set.len(), 2);
+
+    assert_eq!("".parse::<FnvHashResult>(), Err(FnvError::UnsupportedLength { len: 0 }));
+    assert_eq!("0x".parse::<FnvHashResult>(), Err(FnvError::UnsupportedLength { len: 0 }));
+    assert_eq!("abc".parse::<FnvHashResult>(), Err(FnvError::OddLength));
+    assert_eq!("0xabcg".parse::<FnvHashResult>(), Err(FnvError::InvalidHexCharacter { c: 'g', index: 5 }));
+    assert_eq!("ab cd".parse::<FnvHashResult>(), Err(FnvError::OddLength));
+    assert_eq!("ab  cd".parse::<FnvHashResult>(), Err(FnvError::InvalidHexCharacter { c: ' ', index: 2 }));
+    assert_eq!("00".repeat(129).parse::<FnvHashResult>(), Err(FnvError::UnsupportedLength { len: 129 }));
+    assert_eq!(FnvError::OddLength.to_string(), "odd number of hex digits");
+}
+
+#[test]
+fn hash_result_conversion_tests() {
+    use crate::{FnvDigest, FnvError, FnvHashResult};
+    use std::string::ToString;
+
+    let hash: FnvHashResult = Fnv64::hash(b"foobar").into();
+    assert_eq!(u64::try_from(hash), Ok(u64::from(Fnv64::hash(b"foobar"))));
+    assert_eq!(FnvHashResult::from(u64::try_from(hash).unwrap()), hash);
+    assert_eq!(u32::try_from(hash), Err(FnvError::InvalidLength { expected: 4, actual: 8 }));
+    assert_eq!(u128::try_from(hash), Err(FnvError::InvalidLength { expected: 16, actual: 8 }));
+    assert_eq!(FnvDigest::<8>::try_from(hash), Ok(Fnv64::hash(b"foobar")));
+
+    let hash: FnvHashResult = Fnv32::hash(b"foobar").into();
+    assert_eq!(u32::try_from(hash), Ok(u32::from(Fnv32::hash(b"foobar"))));
+    assert_eq!(FnvHashResult::from(0x0102_0304u32).as_bytes(), &[1, 2, 3, 4]);
+
+    let hash: FnvHashResult = Fnv128::hash(b"foobar").into();
+    assert_eq!(FnvHashResult::from(u128::try_from(hash).unwrap()), hash);
+
+    // folded hashes only convert into a digest of their own width
+    let folded: FnvHashResult = Fnv24::hash(b"foobar").into();
+    assert_eq!(u32::try_from(folded), Err(FnvError::InvalidLength { expected: 4, actual: 3 }));
+    assert_eq!(FnvDigest::<3>::try_from(folded), Ok(Fnv24::hash(b"foobar")));
+    assert_eq!(FnvHashResult::from(FnvDigest::from([0x9C, 0xF9, 0xD7])), folded);
+
+    // digests of other widths only come from a hash of that width
+    let odd: FnvHashResult = "0102030405".parse().unwrap();
+    let digest = FnvDigest::<5>::try_from(odd).unwrap();
+    assert_eq!(digest.xor_fold(40), odd);
+    assert_eq!(digest.lazy_mod(1000), odd.lazy_mod(1000));
+    assert_eq!(FnvError::InvalidLength { expected: 4, actual: 3 }.to_string(), "expected 4 bytes, found 3 bytes");
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn hash_result_bigint_conversion_tests() {
+    use crate::{FnvError, FnvHashResult};
+    use crypto_bigint::{U256, U512, U1024};
+
+    let hash: FnvHashResult = Fnv256::hash(b"foobar").into();
+    let int = U256::try_from(hash).unwrap();
+    assert_eq!(int, U256::from(Fnv256::hash(b"foobar")));
+    assert_eq!(FnvHashResult::from(int), hash);
+    assert_eq!(U512::try_from(hash), Err(FnvError::InvalidLength { expected: 64, actual: 32 }));
+
+    let hash: FnvHashResult = Fnv512::hash(b"foobar").into();
+    assert_eq!(FnvHashResult::from(U512::try_from(hash).unwrap()), hash);
+
+    let hash: FnvHashResult = Fnv1024::hash(b"foobar").into();
+    assert_eq!(FnvHashResult::from(U1024::try_from(hash).unwrap()), hash);
+    assert_eq!(U256::try_from(hash), Err(FnvError::InvalidLength { expected: 32, actual: 128 }));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encoding_tests() {
+    use crate::{FnvAlgorithm, FnvError, FnvHashResult};
+
+    let hash: FnvHashResult = Fnv24::hash(b"foobar").into();
+    assert_eq!(hash.to_base32(), "TT45O===");
+    assert_eq!(hash.to_base32_unpadded(), "TT45O");
+    assert_eq!(hash.to_base64url(), "nPnX");
+    assert_eq!(hash.to_base58(), "uj8z");
+    assert_eq!(hash.to_decimal(), "10287575");
+
+    let hash: FnvHashResult = Fnv128::hash(b"foobar").into();
+    assert_eq!(hash.to_base32(), "GQ7BMYTZHRSL63YNGWL3URDPDA======");
+    assert_eq!(hash.to_base64url(), "ND4WYnk8ZL9vDTWXukRvGA");
+    assert_eq!(hash.to_base58(), "7TAbvDwDx3W7g8cC4dwJDm");
+    assert_eq!(hash.to_decimal(), "69442232200936688944159416061740216088");
+    assert_eq!(FnvHashResult::from_base32("gq7bmytzhrsl63yngwl3urdpda"), Ok(hash));
+    assert_eq!(FnvHashResult::from_base64url("ND4WYnk8ZL9vDTWXukRvGA=="), Ok(hash));
+
+    // the 1024-bit hash of "foobar" starts with three zero bytes
+    let hash: FnvHashResult = Fnv1024::hash(b"foobar").into();
+    assert_eq!(
+        hash.to_base58(),
+        "112tQhCrivVWHJ5bKHXBYqbJhzPDmZB3KcT2p5VFE6BrAYJGs5cVtsWdpBzdLLtnAKMicu9tBVxgt7p8HE8XqN3FSvDZ1daf1WXkn9X9ZXPRetHoPpZMpsNFANEa5sWE6akNViXuGxYWyXboqBvgcLTZv9Te1VFmhtzBbfCvXLrFQj"
+    );
+    assert_eq!(
+        hash.to_decimal(),
+        "66345272448972691606537842789683634221744922153397404277165334624174104926186834996019132594579262102780697834624087834766267938584441860581479748935643331317729752954849526618995947326606295018998948730039536731125809542793820084992926619445372440054852987432572530323080346446978015346494142908504240"
+    );
+
+    for algorithm in FnvAlgorithm::ALL {
+        let hash = algorithm.hash(b"foobar");
+        assert_eq!(FnvHashResult::from_base32(&hash.to_base32()), Ok(hash));
+        assert_eq!(FnvHashResult::from_base32(&hash.to_base32_unpadded()), Ok(hash));
+        assert_eq!(FnvHashResult::from_base64url(&hash.to_base64url()), Ok(hash));
+        assert_eq!(FnvHashResult::from_base58(&hash.to_base58()), Ok(hash));
+        assert_eq!(FnvHashResult::from_decimal(&hash.to_decimal(), hash.len()), Ok(hash));
+    }
+
+    let zeros: FnvHashResult = "0000FF01".parse().unwrap();
+    assert_eq!(zeros.to_base32(), "AAAP6AI=");
+    assert_eq!(zeros.to_base64url(), "AAD_AQ");
+    assert_eq!(zeros.to_base58(), "11LQY");
+    assert_eq!(zeros.to_decimal(), "65281");
+    assert_eq!(FnvHashResult::from_base58("11LQY"), Ok(zeros));
+    assert_eq!(FnvHashResult::from_decimal("65281", 4), Ok(zeros));
+    let zero: FnvHashResult = "0000".parse().unwrap();
+    assert_eq!(zero.to_decimal(), "0");
+    assert_eq!(zero.to_base58(), "11");
+    assert_eq!(FnvHashResult::from_base58("11"), Ok(zero));
+    assert_eq!(FnvHashResult::from_decimal("0", 2), Ok(zero));
+
+    assert_eq!(FnvHashResult::from_base32("TT45O=="), Err(FnvError::InvalidEncodedLength { len: 7 }));
+    assert_eq!(FnvHashResult::from_base32("TT4"), Err(FnvError::InvalidEncodedLength { len: 3 }));
+    assert_eq!(FnvHashResult::from_base32("TT1"), Err(FnvError::InvalidEncodedLength { len: 3 }));
+    assert_eq!(FnvHashResult::from_base32("TT15O"), Err(FnvError::InvalidCharacter { c: '1', index: 2 }));
+    assert_eq!(FnvHashResult::from_base64url("nP+X"), Err(FnvError::InvalidCharacter { c: '+', index: 2 }));
+    assert_eq!(FnvHashResult::from_base64url("n=nX"), Err(FnvError::InvalidCharacter { c: '=', index: 1 }));
+
+    // non-zero leftover bits in the final character would give a hash a second encoding
+    assert!(FnvHashResult::from_base64url("C5cS4DrDlY4").is_ok());
+    assert_eq!(FnvHashResult::from_base64url("C5cS4DrDlY5"), Err(FnvError::InvalidCharacter { c: '5', index: 10 }));
+    assert_eq!(FnvHashResult::from_base32("gq7bmytzhrsl63yngwl3urdpdb"), Err(FnvError::InvalidCharacter { c: 'b', index: 25 }));
+    assert_eq!(FnvHashResult::from_base64url(""), Err(FnvError::UnsupportedLength { len: 0 }));
+    assert_eq!(FnvHashResult::from_base58("u0j8z"), Err(FnvError::InvalidCharacter { c: '0', index: 1 }));
+    assert_eq!(FnvHashResult::from_base58("é"), Err(FnvError::InvalidCharacter { c: 'é', index: 0 }));
+    assert_eq!(FnvHashResult::from_base58(""), Err(FnvError::UnsupportedLength { len: 0 }));
+    assert_eq!(FnvHashResult::from_decimal("65536", 2), Err(FnvError::Overflow { len: 2 }));
+    assert_eq!(FnvHashResult::from_decimal("-1", 2), Err(FnvError::InvalidCharacter { c: '-', index: 0 }));
+    assert_eq!(FnvHashResult::from_decimal("1", 0), Err(FnvError::UnsupportedLength { len: 0 }));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_tests() {
+    use crate::{DynFnvHasher, FnvAlgorithm, FnvDigest, FnvHashResult};
+    use std::string::ToString;
+
+    let hash: FnvHashResult = Fnv64::hash(b"foobar").into();
+    let json = serde_json::to_string(&hash).unwrap();
+    assert_eq!(json, std::format!("\"{}\"", hash));
+    assert_eq!(serde_json::from_str::<FnvHashResult>(&json).unwrap(), hash);
+    assert_eq!(serde_json::from_str::<FnvHashResult>("\"0x0b9712e03ac3958e\"").unwrap().len(), 8);
+    assert!(serde_json::from_str::<FnvHashResult>("\"abc\"").is_err());
+
+    let binary = bincode::serialize(&hash).unwrap();
+    assert_eq!(&binary[8..], hash.as_bytes());
+    assert_eq!(bincode::deserialize::<FnvHashResult>(&binary).unwrap(), hash);
+
+    let digest = Fnv1024::hash(b"foobar");
+    assert_eq!(serde_json::to_string(&digest).unwrap(), std::format!("\"{}\"", digest));
+    assert_eq!(serde_json::from_str::<FnvDigest<128>>(&serde_json::to_string(&digest).unwrap()).unwrap(), digest);
+    assert_eq!(bincode::deserialize::<FnvDigest<128>>(&bincode::serialize(&digest).unwrap()).unwrap(), digest);
+    let error = serde_json::from_str::<FnvDigest<4>>(&json).unwrap_err();
+    assert_eq!(error.to_string(), "expected 4 bytes, found 8 bytes");
+
+    // saving and resuming in-progress hashes
+    let mut hasher = Fnv256::new();
+    hasher.update(b"foo");
+    let mut resumed: Fnv256 = serde_json::from_str(&serde_json::to_string(&hasher).unwrap()).unwrap();
+    resumed.update(b"bar");
+    assert_eq!(resumed.finalize(), Fnv256::hash(b"foobar"));
+
+    let mut hasher = Fnv24::new();
+    hasher.update(b"foo");
+    let mut resumed: Fnv24 = bincode::deserialize(&bincode::serialize(&hasher).unwrap()).unwrap();
+    resumed.update(b"bar");
+    assert_eq!(resumed.finalize(), Fnv24::hash(b"foobar"));
+
+    let mut hasher = DynFnvHasher::new(FnvAlgorithm::Fnv0_128);
+    hasher.update(b"foo");
+    let json = serde_json::to_string(&hasher).unwrap();
+    assert!(json.starts_with("[\"fnv0-128\",\""));
+    let mut resumed: DynFnvHasher = serde_json::from_str(&json).unwrap();
+    resumed.update(b"bar");
+    assert_eq!(resumed.finalize(), FnvAlgorithm::Fnv0_128.hash(b"foobar"));
+    let resumed: DynFnvHasher = bincode::deserialize(&bincode::serialize(&hasher).unwrap()).unwrap();
+    assert_eq!(resumed, hasher);
+
+    assert!(serde_json::from_str::<DynFnvHasher>("[\"fnv1a-64\",\"00\"]").is_err());
+    assert!(serde_json::from_str::<DynFnvHasher>("[\"fnv2-64\",\"0000000000000000\"]").is_err());
+
+    let tagged = crate::FnvTaggedHash::compute(FnvAlgorithm::Fnv1a512, b"foobar");
+    let json = serde_json::to_string(&tagged).unwrap();
+    assert_eq!(json, std::format!("\"{}\"", tagged));
+    assert_eq!(serde_json::from_str::<crate::FnvTaggedHash>(&json).unwrap(), tagged);
+    let binary = bincode::serialize(&tagged).unwrap();
+    assert_eq!(&binary[8..], &tagged.to_multihash()[..]);
+    assert_eq!(bincode::deserialize::<crate::FnvTaggedHash>(&binary).unwrap(), tagged);
+    let mut oversized = tagged.to_multihash()[..4].to_vec();
+    oversized.extend_from_slice(&[0xC8, 0x01]);
+    oversized.extend_from_slice(&[0; 200]);
+    assert!(bincode::deserialize::<crate::FnvTaggedHash>(&bincode::serialize(&oversized).unwrap()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn tagged_hash_tests() {
+    use crate::{FnvAlgorithm, FnvError, FnvTaggedHash, FnvVariant};
+    use std::string::ToString;
+
+    let tagged = FnvTaggedHash::compute(FnvAlgorithm::Fnv1a64, b"Hash this!testing123");
+    assert_eq!(tagged.to_string(), "fnv1a-64:AD2808D0C15A663E");
+    assert_eq!("FNV1A-64:ad2808d0c15a663e".parse(), Ok(tagged));
+    assert!(tagged.verify(b"Hash this!testing123"));
+    assert!(!tagged.verify(b"Hash this!testing124"));
+
+    assert_eq!(FnvAlgorithm::Fnv1a64.multihash_code(), 0x30_2040);
+    assert_eq!(FnvAlgorithm::Fnv0_1024.multihash_code(), 0x30_0400);
+    assert_eq!(FnvAlgorithm::Fnv1_32.variant(), FnvVariant::Fnv1);
+
+    for algorithm in FnvAlgorithm::ALL {
+        let tagged = FnvTaggedHash::compute(*algorithm, b"foobar");
+        assert_eq!(tagged.hash(), algorithm.hash(b"foobar"));
+        assert_eq!(tagged.to_string().parse(), Ok(tagged));
+        assert_eq!(FnvTaggedHash::from_sri(&tagged.to_sri()), Ok(tagged));
+        assert_eq!(FnvTaggedHash::from_multihash(&tagged.to_multihash()), Ok(tagged));
+        assert_eq!(FnvAlgorithm::from_multihash_code(algorithm.multihash_code()), Some(*algorithm));
+        assert!(tagged.verify(b"foobar"));
+    }
+
+    let tagged = FnvTaggedHash::compute(FnvAlgorithm::Fnv1_1024, b"foobar");
+    let multihash = tagged.to_multihash();
+    assert_eq!(&multihash[..5], &[0x80, 0xA8, 0xC0, 0x01, 0x80]);
+    assert_eq!(multihash.len(), 4 + 2 + 128);
+
+    assert_eq!(tagged.to_sri().len(), "fnv1-1024-".len() + 172);
+    assert_eq!(FnvTaggedHash::from_sri("fnv1a-64-rSgI0MFaZj4=?ct=text/plain").unwrap().to_string(), "fnv1a-64:AD2808D0C15A663E");
+    assert_eq!(FnvTaggedHash::from_sri("fnv1a-64-rSgI0MFaZj4"), "fnv1a-64:AD2808D0C15A663E".parse());
+    assert_eq!(FnvTaggedHash::from_sri("sha256-rSgI0MFaZj4="), Err(FnvError::UnknownAlgorithm));
+    assert_eq!(FnvTaggedHash::from_sri("fnv1a-64-rSg_0MFaZj4="), Err(FnvError::InvalidCharacter { c: '_', index: 3 }));
+    assert_eq!(FnvTaggedHash::from_sri("fnv1a-64-C5cS4DrDlY5="), Err(FnvError::InvalidCharacter { c: '5', index: 10 }));
+
+    assert_eq!("AD2808D0C15A663E".parse::<FnvTaggedHash>(), Err(FnvError::UnknownAlgorithm));
+    assert_eq!("fnv1a-128:AD2808D0C15A663E".parse::<FnvTaggedHash>(), Err(FnvError::InvalidLength { expected: 16, actual: 8 }));
+    assert_eq!("fnv1a-64:AD2808D0C15A663".parse::<FnvTaggedHash>(), Err(FnvError::OddLength));
+
+    let multihash = FnvTaggedHash::compute(FnvAlgorithm::Fnv1a32, b"foobar").to_multihash();
+    assert_eq!(FnvTaggedHash::from_multihash(&multihash[..2]), Err(FnvError::InvalidMultihash));
+    assert_eq!(FnvTaggedHash::from_multihash(&multihash[..6]), Err(FnvError::InvalidLength { expected: 4, actual: 1 }));
+    assert_eq!(FnvTaggedHash::from_multihash(&[0x12, 0x04, 0, 0, 0, 0]), Err(FnvError::UnknownAlgorithm));
+    assert_eq!(FnvTaggedHash::from_multihash(&[0xFF; 12]), Err(FnvError::InvalidMultihash));
+
+    // a length prefix wider than any hash is rejected before the digest is read
+    let mut oversized = multihash[..4].to_vec();
+    oversized.extend_from_slice(&[0xC8, 0x01]);
+    oversized.extend_from_slice(&[0; 200]);
+    assert_eq!(FnvTaggedHash::from_multihash(&oversized), Err(FnvError::InvalidLength { expected: 4, actual: 200 }));
+    let mut mismatched = multihash[..4].to_vec();
+    mismatched.push(8);
+    mismatched.extend_from_slice(&[0; 8]);
+    assert_eq!(FnvTaggedHash::from_multihash(&mismatched), Err(FnvError::InvalidLength { expected: 4, actual: 8 }));
+}
@@ -0,0 +1,246 @@
+//! Fixed-width unsigned integers for the 256, 512 and 1024-bit FNV variants.
+//!
+//! Only the handful of operations FNV needs are implemented, all as `const fn`s so the wide
+//! hashers can hash at compile time too. Every multiplication in FNV is by one of the FNV
+//! primes, which are a power of two plus a small constant. [`WideUint::wrapping_mul_sparse`]
+//! turns such a multiplication into a shift plus a single-limb multiply-add.
+
+use core::cmp::Ordering;
+
+/// An unsigned integer of `LIMBS * 64` bits, stored as little-endian `u64` limbs.
+///
+/// This is the [`FnvWord`](crate::FnvWord) of the 256, 512 & 1024-bit hashers. Build one with
+/// [`from_be_slice`](WideUint::from_be_slice) to use custom wide [`FnvParams`](crate::FnvParams).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WideUint<const LIMBS: usize>(pub(crate) [u64; LIMBS]);
+
+impl<const LIMBS: usize> WideUint<LIMBS> {
+    pub(crate) const ZERO: Self = WideUint([0; LIMBS]);
+    pub(crate) const MAX: Self = WideUint([u64::MAX; LIMBS]);
+
+    /// Reads a big-endian integer of exactly `LIMBS * 8` bytes.
+    ///
+    /// # Panics:
+    ///
+    /// Panics if `bytes` is not `LIMBS * 8` bytes long.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// # use fnv_rs::WideUint;
+    /// const PRIME: WideUint<4> = WideUint::from_be_slice(&[
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x63,
+    /// ]);
+    /// ```
+    pub const fn from_be_slice(bytes: &[u8]) -> Self {
+        assert!(bytes.len() == LIMBS * 8, "slice length does not match the integer width");
+
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < bytes.len() {
+            let limb = LIMBS - 1 - i / 8;
+            limbs[limb] = (limbs[limb] << 8) | bytes[i] as u64;
+            i += 1;
+        }
+
+        WideUint(limbs)
+    }
+
+    /// Writes this integer as `N` big-endian bytes, where `N` must be `LIMBS * 8`.
+    pub(crate) const fn to_be_bytes<const N: usize>(self) -> [u8; N] {
+        assert!(N == LIMBS * 8, "byte length does not match the integer width");
+
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            bytes[i] = (self.0[LIMBS - 1 - i / 8] >> (56 - (i % 8) * 8)) as u8;
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Xors `byte` into the lowest 8 bits.
+    #[inline]
+    pub(crate) const fn xor_byte(mut self, byte: u8) -> Self {
+        self.0[0] ^= byte as u64;
+        self
+    }
+
+    pub(crate) const fn wrapping_add(&self, rhs: &Self) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        let mut carry = false;
+
+        let mut i = 0;
+        while i < LIMBS {
+            let (sum, overflow_a) = self.0[i].overflowing_add(rhs.0[i]);
+            let (sum, overflow_b) = sum.overflowing_add(carry as u64);
+            limbs[i] = sum;
+            carry = overflow_a || overflow_b;
+            i += 1;
+        }
+
+        WideUint(limbs)
+    }
+
+    pub(crate) const fn wrapping_sub(&self, rhs: &Self) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        let mut borrow = false;
+
+        let mut i = 0;
+        while i < LIMBS {
+            let (diff, overflow_a) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (diff, overflow_b) = diff.overflowing_sub(borrow as u64);
+            limbs[i] = diff;
+            borrow = overflow_a || overflow_b;
+            i += 1;
+        }
+
+        WideUint(limbs)
+    }
+
+    /// Schoolbook multiplication, truncated to `LIMBS` limbs.
+    ///
+    /// Zero limbs of `rhs` are skipped, so multiplying by an FNV prime costs two passes over the
+    /// limbs rather than `LIMBS`.
+    pub(crate) const fn wrapping_mul(&self, rhs: &Self) -> Self {
+        let mut limbs = [0u64; LIMBS];
+
+        let mut j = 0;
+        while j < LIMBS {
+            let factor = rhs.0[j] as u128;
+            if factor != 0 {
+                let mut carry = 0u128;
+                let mut i = 0;
+                while i + j < LIMBS {
+                    let product = self.0[i] as u128 * factor + limbs[i + j] as u128 + carry;
+                    limbs[i + j] = product as u64;
+                    carry = product >> 64;
+                    i += 1;
+                }
+            }
+            j += 1;
+        }
+
+        WideUint(limbs)
+    }
+
+    /// Multiplies by `2^prime.shift + prime.low`, truncated to `LIMBS` limbs.
+    ///
+    /// This gives the same result as [`wrapping_mul`](WideUint::wrapping_mul) by the full prime,
+    /// with one multiply per limb instead of two and no carries through a second product.
+    #[inline]
+    pub(crate) const fn wrapping_mul_sparse(&self, prime: &SparsePrime) -> Self {
+        let limb_shift = (prime.shift / 64) as usize;
+        let bit_shift = prime.shift % 64;
+
+        // `self * low`, which only needs one pass of single-limb multiplies
+        let mut limbs = [0u64; LIMBS];
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < LIMBS {
+            let product = self.0[i] as u128 * prime.low as u128 + carry as u128;
+            limbs[i] = product as u64;
+            carry = (product >> 64) as u64;
+            i += 1;
+        }
+
+        // plus `self << shift`, which leaves the limbs below `limb_shift` untouched
+        let mut carry = false;
+        let mut i = limb_shift;
+        while i < LIMBS {
+            let mut shifted = self.0[i - limb_shift] << bit_shift;
+            if bit_shift > 0 && i > limb_shift {
+                shifted |= self.0[i - limb_shift - 1] >> (64 - bit_shift);
+            }
+
+            let (sum, overflow_a) = limbs[i].overflowing_add(shifted);
+            let (sum, overflow_b) = sum.overflowing_add(carry as u64);
+            limbs[i] = sum;
+            carry = overflow_a || overflow_b;
+            i += 1;
+        }
+
+        WideUint(limbs)
+    }
+
+    /// Returns the inverse of `self` modulo `2^(LIMBS * 64)`.
+    ///
+    /// Panics if `self` is even, as only odd numbers have an inverse.
+    pub(crate) const fn wrapping_inverse(&self) -> Self {
+        assert!(self.0[0] % 2 == 1, "only odd numbers have an inverse");
+
+        // Newton's iteration: an odd number is its own inverse modulo 2^3, and each step
+        // doubles the number of correct bits
+        let mut two = Self::ZERO;
+        two.0[0] = 2;
+
+        let mut inverse = *self;
+        let mut bits = 3;
+        while bits < LIMBS * 64 {
+            inverse = inverse.wrapping_mul(&two.wrapping_sub(&self.wrapping_mul(&inverse)));
+            bits *= 2;
+        }
+
+        inverse
+    }
+
+    /// Returns `self % modulus`.
+    pub(crate) const fn rem_u64(&self, modulus: u64) -> u64 {
+        let modulus = modulus as u128;
+
+        let mut rem = 0u128;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            rem = ((rem << 64) | self.0[i] as u128) % modulus;
+        }
+
+        rem as u64
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for WideUint<LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for WideUint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the most significant limb is last
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+/// A prime of the form `2^shift + low`, the shape of every wide FNV prime.
+#[derive(Clone, Copy, Debug)]
+pub struct SparsePrime {
+    shift: u32,
+    low: u64,
+}
+
+impl SparsePrime {
+    /// Splits `prime` into its power of two and the small constant in its lowest limb.
+    ///
+    /// Panics if `prime` is not `2^shift + low` with `shift` above the lowest limb.
+    pub(crate) const fn new<const LIMBS: usize>(prime: &WideUint<LIMBS>) -> Self {
+        let mut top = LIMBS - 1;
+        while prime.0[top] == 0 {
+            top -= 1;
+        }
+        assert!(top > 0 && prime.0[top].is_power_of_two(), "not a sparse prime");
+
+        let mut i = 1;
+        while i < top {
+            assert!(prime.0[i] == 0, "not a sparse prime");
+            i += 1;
+        }
+
+        SparsePrime {
+            shift: top as u32 * 64 + prime.0[top].trailing_zeros(),
+            low: prime.0[0],
+        }
+    }
+}